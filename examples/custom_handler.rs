@@ -0,0 +1,68 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use crossterm::style::Stylize;
+use rucline::actions::{Action, Direction, Editor, Event, Overrider};
+use rucline::prompt::{Builder, Prompt};
+use rucline::Buffer;
+use rucline::Outcome::{Accepted, Eof};
+
+const SHOUT: u32 = 0;
+const RESTART: u32 = 1;
+
+// An overrider that binds custom actions to CTRL+T and CTRL+R
+struct CustomKeys;
+
+impl Overrider for CustomKeys {
+    fn override_for(&self, event: Event, _: &Buffer) -> Option<Action> {
+        if event.modifiers == KeyModifiers::CONTROL {
+            match event.code {
+                KeyCode::Char('t') => return Some(Action::Custom(SHOUT)),
+                KeyCode::Char('r') => return Some(Action::Custom(RESTART)),
+                _ => {}
+            }
+        }
+
+        // Fallback to default action
+        None
+    }
+}
+
+// Runs the custom actions against the editor
+fn handle(id: u32, editor: &mut dyn Editor) -> Action {
+    match id {
+        // Shout the line and accept it right away
+        SHOUT => {
+            let shout = format!("{}!", editor.buffer().to_uppercase());
+            *editor.buffer_mut() = shout.into();
+            Action::Accept
+        }
+        // Start over and show what can be done
+        RESTART => {
+            editor.buffer_mut().clear();
+            editor.suggest(Direction::Forward);
+            Action::NoOp
+        }
+        _ => Action::NoOp,
+    }
+}
+
+fn main() {
+    // Simulate possible commands
+    let possible_commands = vec!["run", "walk", "fly"];
+
+    // Simulate a history of previous inputs
+    let command_history = vec!["run", "fly"];
+
+    match Prompt::from(format!("{}> ", "vai".green()))
+        .erase_after_read(true)
+        .suggester_ref(&possible_commands)
+        .completer(command_history)
+        .overrider(CustomKeys)
+        .handler_fn(handle)
+        .read_line()
+    {
+        Ok(Accepted(command)) => println!("Ok! Will {command}"),
+        // CTRL+D on an empty line
+        Ok(Eof) => println!("Bye!"),
+        _ => eprintln!("{} no command", "Error".red()),
+    }
+}
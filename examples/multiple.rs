@@ -47,6 +47,7 @@ fn main() {
                     Outcome::Canceled(_) => {
                         buffer = command.into();
                     }
+                    Outcome::Eof | Outcome::Interrupted(_) => break,
                 }
             }
         } else {
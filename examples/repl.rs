@@ -0,0 +1,48 @@
+use rucline::completion::{Command, Flag, Paths};
+use rucline::prompt::Prompt;
+use rucline::repl::Repl;
+
+fn main() {
+    let mut counter = 0;
+
+    let mut repl = Repl::new(Prompt::from("repl> "))
+        .with_command(
+            Command::new("echo")
+                .with_description("Print the arguments")
+                .with_flag(Flag::long("upper").with_short('u')),
+            |args| {
+                let is_flag = |arg: &&String| *arg == "--upper" || *arg == "-u";
+                let words = args.iter().filter(|arg| !is_flag(arg));
+                let line = words.cloned().collect::<Vec<_>>().join(" ");
+                if args.iter().any(|arg| is_flag(&arg)) {
+                    println!("{}", line.to_uppercase());
+                } else {
+                    println!("{line}");
+                }
+                Ok(())
+            },
+        )
+        .with_command(
+            Command::new("count").with_description("Count the calls"),
+            |_| {
+                counter += 1;
+                println!("{counter}");
+                Ok(())
+            },
+        )
+        .with_command(
+            Command::new("size")
+                .with_description("Print the size of files")
+                .with_argument(Paths::new()),
+            |args| {
+                for path in args {
+                    println!("{path}: {} bytes", std::fs::metadata(path)?.len());
+                }
+                Ok(())
+            },
+        );
+
+    if let Err(error) = repl.run() {
+        eprintln!("{error}");
+    }
+}
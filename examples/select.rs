@@ -0,0 +1,30 @@
+use rucline::prompt::{Confirm, MultiSelect, Select};
+use rucline::Outcome::Accepted;
+
+fn main() {
+    let sizes = ["small", "medium", "large"];
+    let toppings = ["cheese", "olives", "mushrooms", "peppers", "onions", "ham"];
+
+    let Ok(Accepted(size)) = Select::new(sizes).prompt("Size: ").read() else {
+        return;
+    };
+
+    let Ok(Accepted(picked)) = MultiSelect::new(toppings)
+        .prompt("Toppings: ")
+        .height(4)
+        .read()
+    else {
+        return;
+    };
+
+    let picked = picked.into_iter().map(|index| toppings[index]);
+    println!(
+        "A {} pizza with {}",
+        sizes[size],
+        picked.collect::<Vec<_>>().join(", ")
+    );
+
+    if let Ok(Accepted(true)) = Confirm::from("Order? ").default_answer(true).read() {
+        println!("Ordered!");
+    }
+}
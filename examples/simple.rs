@@ -15,6 +15,8 @@ fn main() {
             "https://www.startpage.com/",
             "https://www.google.com/",
         ])
+        // Hint at what to type
+        .placeholder("type a URL")
         //Block until value is ready
         .read_line()
     {
@@ -65,13 +65,39 @@
 //! If the feature `config-serde` is enabled, [`KeyBindings`] can be serialized, stored, and loaded
 //! at runtime.
 //!
+//! # Custom actions
+//!
+//! Behavior that is not covered by the built-in actions can be bound to an [`Event`] with
+//! [`Custom`]. The [`prompt`] routes custom actions to a [`Handler`], which gets mutable access to
+//! the [`Editor`] and returns the [`Action`] to take next.
+//!
+//! ```
+//! use rucline::actions::{Action, Editor, Event, KeyBindings, KeyCode};
+//! use rucline::prompt::{Builder, Prompt};
+//!
+//! const UPPERCASE: u32 = 0;
+//!
+//! let mut bindings = KeyBindings::new();
+//! bindings.insert(Event::from(KeyCode::F(1)), Action::Custom(UPPERCASE));
+//!
+//! let prompt = Prompt::new()
+//!     .overrider(bindings)
+//!     .handler_fn(|id, editor: &mut dyn Editor| {
+//!         if id == UPPERCASE {
+//!             let upper = editor.buffer().to_uppercase();
+//!             *editor.buffer_mut() = upper.into();
+//!         }
+//!         Action::NoOp
+//!     });
+//! ```
+//!
 //! # Default behavior
 //!
 //! In the absence of [`KeyBindings`] or an entry for a given [`Event`], the default behavior
 //! will be as follows:
 //!
 //! ```no_run
-//! # fn default_action(event: rucline::actions::Event) -> rucline::actions::Action {
+//! # fn default_action(event: rucline::actions::Event, buffer: &rucline::Buffer) -> rucline::actions::Action {
 //! # use rucline::actions::{Action::*, Direction::*, KeyCode, Range::*, Scope::* };
 //! # match event.code {
 //! KeyCode::Enter => Accept,
@@ -84,11 +110,16 @@
 //! KeyCode::Left => Move(Single, Backward),
 //! KeyCode::Home => Move(Line, Backward),
 //! KeyCode::End => Move(Line, Forward),
+//! KeyCode::Up => Recall(Backward),
+//! KeyCode::Down => Recall(Forward),
 //! KeyCode::Char(c) => {
 //!     if event.modifiers == crossterm::event::KeyModifiers::CONTROL {
 //!         match c {
-//!             'm' | 'd' => Accept,
-//!             'c' => Cancel,
+//!             'm' => Accept,
+//!             'd' if buffer.is_empty() => Eof,
+//!             'd' => Delete(Relative(Single, Forward)),
+//!             'c' => Interrupt,
+//!             'x' => EditExternally,
 //!
 //!             'b' => Move(Single, Backward),
 //!             'f' => Move(Single, Forward),
@@ -117,9 +148,31 @@
 //! # }}
 //! ```
 //!
+//! While the suggestion options are being displayed, the arrow keys navigate them instead:
+//!
+//! ```no_run
+//! # fn menu_action(event: rucline::actions::Event, layout: rucline::prompt::Layout) -> Option<rucline::actions::Action> {
+//! # use rucline::actions::{Action::*, Direction::*, KeyCode};
+//! # use rucline::prompt::Layout;
+//! # match (event.code, layout) {
+//! (KeyCode::Up, _) => Some(SelectRow(Backward)),
+//! (KeyCode::Down, _) => Some(SelectRow(Forward)),
+//! (KeyCode::Left, Layout::Grid) => Some(SelectColumn(Backward)),
+//! (KeyCode::Right, Layout::Grid) => Some(SelectColumn(Forward)),
+//! _ => None,
+//! # }}
+//! ```
+//!
+//! While an in-line completion is rendered at the cursor, the keys that move the cursor forward
+//! [`Complete`] it instead, be it at the end of the line or for the word under the cursor.
+//!
 //!  > Check the test cases for [`Buffer`] to see how line edits are expected to behave.
 //!
 //! [`Action`]: enum.Action.html
+//! [`Complete`]: enum.Action.html#variant.Complete
+//! [`Custom`]: enum.Action.html#variant.Custom
+//! [`Editor`]: trait.Editor.html
+//! [`Handler`]: trait.Handler.html
 //! [`Event`]: type.Event.html
 //! [`KeyBindings`]: type.KeyBindings.html
 //! [`NoOp`]: enum.Action.html#variant.NoOp
@@ -127,9 +180,11 @@
 //! [`prompt`]: ../prompt/index.html
 //! [`Buffer`]: ../buffer/struct.Buffer.html
 
+use crate::completion::Completion;
+use crate::prompt::Layout;
 use crate::Buffer;
 
-#[cfg(feature = "serde")]
+#[cfg(feature = "config-serde")]
 use serde::{Deserialize, Serialize};
 
 /// Alias to `crossterm::event::KeyEvent` from [`crossterm`](https://docs.rs/crossterm/).
@@ -143,7 +198,7 @@ pub type KeyBindings = std::collections::HashMap<Event, Action>;
 
 /// An action that can be performed while reading a line
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "config-serde", derive(Serialize, Deserialize))]
 pub enum Action {
     /// Write a single character where the cursor is
     Write(char),
@@ -153,20 +208,39 @@ pub enum Action {
     Move(Range, Direction),
     /// Trigger the [`suggester`](../completion/trait.Suggester.html)
     Suggest(Direction),
+    /// Select the suggestion option in the previous or next row, triggering the
+    /// [`suggester`](../completion/trait.Suggester.html) if needed
+    SelectRow(Direction),
+    /// Select the suggestion option in the previous or next column, if the suggestion options
+    /// are laid out in a [`Grid`](../prompt/enum.Layout.html#variant.Grid)
+    SelectColumn(Direction),
     /// Accept [`Range`](enum.Range.html) from the current completion presented by
     /// [`completer`](../completion/trait.Completer.html), if any
     Complete(Range),
+    /// Replace the line with the previous ([`Backward`](enum.Direction.html#variant.Backward)) or
+    /// next ([`Forward`](enum.Direction.html#variant.Forward)) entry of the
+    /// [`history`](../prompt/struct.Options.html#structfield.history)
+    Recall(Direction),
     /// Accept the current line
     Accept,
     /// Cancel the suggestions, if any. Else, discard the whole line
     Cancel,
+    /// Signal the end of the input, e.g. to exit a REPL
+    Eof,
+    /// Interrupt the input, discarding the whole line even if there are suggestions
+    Interrupt,
+    /// Run the [`Handler`](trait.Handler.html) with the given identifier
+    Custom(u32),
+    /// Edit the line in the external editor set by `$VISUAL` or `$EDITOR`, falling back to `vi`
+    /// (`notepad` on Windows), and take the edited text back into the line
+    EditExternally,
     /// Do nothing and wait for the next [`Event`](type.Event.html)
     NoOp,
 }
 
 /// The scope an [`Action`](enum.Action.html) should be applied on
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "config-serde", derive(Serialize, Deserialize))]
 pub enum Scope {
     /// Represents a whole line
     WholeLine,
@@ -179,7 +253,7 @@ pub enum Scope {
 
 /// The range an [`Action`](enum.Action.html) should extend for
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "config-serde", derive(Serialize, Deserialize))]
 pub enum Range {
     /// Represents the remainder of the line
     Line,
@@ -191,7 +265,7 @@ pub enum Range {
 
 /// The direction an [`Action`](enum.Action.html) may take
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "config-serde", derive(Serialize, Deserialize))]
 pub enum Direction {
     /// Represents a "right" or "down" direction
     Forward,
@@ -234,6 +308,156 @@ pub trait Overrider {
     fn override_for(&self, event: Event, buffer: &Buffer) -> Option<Action>;
 }
 
+/// The state of the [`prompt`] while reading a line, as seen by a [`Handler`] or an
+/// [`EditorOverrider`].
+///
+/// Changes are rendered once the [`Handler`] or the [`EditorOverrider`] returns, before any of the
+/// returned actions are taken.
+///
+/// [`EditorOverrider`]: trait.EditorOverrider.html
+/// [`Handler`]: trait.Handler.html
+/// [`prompt`]: ../prompt/index.html
+pub trait Editor {
+    /// Returns the line buffer.
+    fn buffer(&self) -> &Buffer;
+
+    /// Returns the line buffer for editing.
+    ///
+    /// If a suggestion is selected, it is accepted into the buffer first, as if the line had been
+    /// edited with a [`Write`].
+    ///
+    /// [`Write`]: enum.Action.html#variant.Write
+    fn buffer_mut(&mut self) -> &mut Buffer;
+
+    /// Returns true if the suggestions are open.
+    fn is_suggesting(&self) -> bool;
+
+    /// Returns the in-line completion currently rendered, if any.
+    fn completion(&self) -> Option<&Completion<'_>>;
+
+    /// Opens the suggestions or, if they are already open, cycles through them in `direction`.
+    fn suggest(&mut self, direction: Direction);
+
+    /// Closes the suggestions, if any, without accepting the selected one.
+    fn cancel_suggestion(&mut self);
+
+    /// Returns the position in the [`history`] of the entry last recalled, counting from the most
+    /// recent one, or `None` if no entry was recalled.
+    ///
+    /// [`history`]: ../prompt/struct.Options.html#structfield.history
+    fn history_position(&self) -> Option<usize>;
+}
+
+/// Handles the [`Custom`] actions.
+///
+/// This trait has a convenience implementation for closures.
+///
+/// # Example
+///
+/// ```
+/// use rucline::actions::{Action, Direction, Editor, Handler};
+///
+/// struct ClearAndSuggest;
+///
+/// impl Handler for ClearAndSuggest {
+///     fn handle(&self, _: u32, editor: &mut dyn Editor) -> Action {
+///         editor.buffer_mut().clear();
+///         editor.suggest(Direction::Forward);
+///         Action::NoOp
+///     }
+/// }
+/// ```
+///
+/// [`Custom`]: enum.Action.html#variant.Custom
+pub trait Handler {
+    /// Handles the [`Custom`] action with the given `id`.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the [`Custom`] action.
+    /// * [`editor`] - The state of the prompt, which may be changed.
+    ///
+    /// # Return
+    /// * The [`Action`] to take next, e.g. [`Accept`], [`Cancel`], or [`NoOp`] to continue reading
+    ///   the line. A returned [`Custom`] action is handled in turn.
+    ///
+    /// [`Accept`]: enum.Action.html#variant.Accept
+    /// [`Action`]: enum.Action.html
+    /// [`Cancel`]: enum.Action.html#variant.Cancel
+    /// [`Custom`]: enum.Action.html#variant.Custom
+    /// [`NoOp`]: enum.Action.html#variant.NoOp
+    /// [`editor`]: trait.Editor.html
+    fn handle(&self, id: u32, editor: &mut dyn Editor) -> Action;
+}
+
+impl<F> Handler for F
+where
+    F: Fn(u32, &mut dyn Editor) -> Action,
+{
+    fn handle(&self, id: u32, editor: &mut dyn Editor) -> Action {
+        self(id, editor)
+    }
+}
+
+/// Overrides the behavior for a given [`Event`] with a sequence of actions, with access to the
+/// [`Editor`].
+///
+/// Every [`Overrider`], including [`KeyBindings`] and closures, is also an `EditorOverrider`
+/// that overrides an event with a single action.
+///
+/// # Example
+///
+/// ```
+/// use rucline::actions::{Action, Direction, Editor, EditorOverrider, Event, KeyCode, Range};
+/// use rucline::prompt::{Builder, Prompt};
+///
+/// // Comments out the line and accepts it, unless suggestions are open
+/// struct CommentOut;
+///
+/// impl EditorOverrider for CommentOut {
+///     fn override_with(&self, event: Event, editor: &mut dyn Editor) -> Option<Vec<Action>> {
+///         if event == Event::from(KeyCode::F(2)) && !editor.is_suggesting() {
+///             Some(vec![
+///                 Action::Move(Range::Line, Direction::Backward),
+///                 Action::Write('#'),
+///                 Action::Accept,
+///             ])
+///         } else {
+///             None
+///         }
+///     }
+/// }
+///
+/// let prompt = Prompt::new().overrider(CommentOut);
+/// ```
+///
+/// [`Editor`]: trait.Editor.html
+/// [`Event`]: type.Event.html
+/// [`KeyBindings`]: type.KeyBindings.html
+/// [`Overrider`]: trait.Overrider.html
+pub trait EditorOverrider {
+    /// Overrides the behavior for the given [`Event`].
+    ///
+    /// # Arguments
+    /// * [`event`] - The incoming event to be processed.
+    /// * [`editor`] - The current state of the prompt, which may be changed.
+    ///
+    /// # Return
+    /// * The actions to take, in order, or `None` for the default behavior. Taking the actions
+    ///   stops after an action that ends the prompt, e.g. [`Accept`].
+    ///
+    /// [`Accept`]: enum.Action.html#variant.Accept
+    /// [`Event`]: type.Event.html
+    /// [`editor`]: trait.Editor.html
+    fn override_with(&self, event: Event, editor: &mut dyn Editor) -> Option<Vec<Action>>;
+}
+
+impl<O: Overrider + ?Sized> EditorOverrider for O {
+    fn override_with(&self, event: Event, editor: &mut dyn Editor) -> Option<Vec<Action>> {
+        self.override_for(event, editor.buffer())
+            .map(|action| vec![action])
+    }
+}
+
 impl Overrider for KeyBindings {
     fn override_for(&self, event: Event, _: &Buffer) -> Option<Action> {
         self.get(&event).copied()
@@ -249,15 +473,32 @@ where
     }
 }
 
-pub(super) fn action_for<O: Overrider + ?Sized>(
+pub(super) fn actions_for<O, E>(
     overrider: Option<&O>,
     event: Event,
-    buffer: &Buffer,
-) -> Action {
+    editor: &mut E,
+    menu: Option<Layout>,
+    completing: bool,
+) -> Vec<Action>
+where
+    O: EditorOverrider + ?Sized,
+    E: Editor,
+{
     overrider
-        .as_ref()
-        .and_then(|b| b.override_for(event, buffer))
-        .unwrap_or_else(|| default_action(event, buffer))
+        .and_then(|o| o.override_with(event, editor))
+        .unwrap_or_else(|| {
+            let buffer = editor.buffer();
+            vec![menu
+                .and_then(|layout| menu_action(event, layout))
+                .or_else(|| {
+                    if completing {
+                        completion_action(event, buffer)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or_else(|| default_action(event, buffer))]
+        })
 }
 
 #[inline]
@@ -283,8 +524,31 @@ fn complete_if_at_end_else_move(buffer: &Buffer, range: Range) -> Action {
     }
 }
 
-fn default_action(event: Event, buffer: &Buffer) -> Action {
-    use Action::{Accept, Cancel, Delete, Move, NoOp, Suggest, Write};
+pub(super) fn menu_action(event: Event, layout: Layout) -> Option<Action> {
+    use Action::{SelectColumn, SelectRow};
+    use Direction::{Backward, Forward};
+
+    match (event.code, layout) {
+        (KeyCode::Up, _) => Some(SelectRow(Backward)),
+        (KeyCode::Down, _) => Some(SelectRow(Forward)),
+        (KeyCode::Left, Layout::Grid) => Some(SelectColumn(Backward)),
+        (KeyCode::Right, Layout::Grid) => Some(SelectColumn(Forward)),
+        _ => None,
+    }
+}
+
+fn completion_action(event: Event, buffer: &Buffer) -> Option<Action> {
+    match default_action(event, buffer) {
+        Action::Move(Range::Word, Direction::Forward) => Some(Action::Complete(Range::Word)),
+        Action::Move(_, Direction::Forward) => Some(Action::Complete(Range::Line)),
+        _ => None,
+    }
+}
+
+pub(super) fn default_action(event: Event, buffer: &Buffer) -> Action {
+    use Action::{
+        Accept, Cancel, Delete, EditExternally, Eof, Interrupt, Move, NoOp, Recall, Suggest, Write,
+    };
     use Direction::{Backward, Forward};
     use Range::{Line, Single, Word};
     use Scope::{Relative, WholeLine, WholeWord};
@@ -300,11 +564,16 @@ fn default_action(event: Event, buffer: &Buffer) -> Action {
         KeyCode::Left => Move(Single, Backward),
         KeyCode::Home => Move(Line, Backward),
         KeyCode::End => complete_if_at_end_else_move(buffer, Line),
+        KeyCode::Up => Recall(Backward),
+        KeyCode::Down => Recall(Forward),
         KeyCode::Char(c) => {
             if control_pressed(&event) {
                 match c {
-                    'm' | 'd' => Accept,
-                    'c' => Cancel,
+                    'm' => Accept,
+                    'd' if buffer.is_empty() => Eof,
+                    'd' => Delete(Relative(Single, Forward)),
+                    'c' => Interrupt,
+                    'x' => EditExternally,
 
                     'b' => Move(Single, Backward),
                     'f' => complete_if_at_end_else_move(buffer, Single),
@@ -335,7 +604,56 @@ fn default_action(event: Event, buffer: &Buffer) -> Action {
 
 #[cfg(test)]
 mod test {
-    use super::{action_for, default_action, Action, Buffer, Direction, Event, KeyCode, Range};
+    use super::{
+        default_action, Action, Buffer, Completion, Direction, Editor, EditorOverrider, Event,
+        KeyCode, Layout, Range,
+    };
+
+    struct Line(Buffer);
+
+    impl Editor for Line {
+        fn buffer(&self) -> &Buffer {
+            &self.0
+        }
+
+        fn buffer_mut(&mut self) -> &mut Buffer {
+            &mut self.0
+        }
+
+        fn is_suggesting(&self) -> bool {
+            false
+        }
+
+        fn completion(&self) -> Option<&Completion<'_>> {
+            None
+        }
+
+        fn suggest(&mut self, _: Direction) {}
+
+        fn cancel_suggestion(&mut self) {}
+
+        fn history_position(&self) -> Option<usize> {
+            None
+        }
+    }
+
+    fn action_for<O: EditorOverrider + ?Sized>(
+        overrider: Option<&O>,
+        event: Event,
+        buffer: &Buffer,
+        menu: Option<Layout>,
+        completing: bool,
+    ) -> Action {
+        let actions = super::actions_for(
+            overrider,
+            event,
+            &mut Line(buffer.clone()),
+            menu,
+            completing,
+        );
+        assert_eq!(actions.len(), 1);
+        actions[0]
+    }
 
     #[test]
     fn should_complete_if_at_end() {
@@ -375,23 +693,141 @@ mod test {
         );
     }
 
+    #[test]
+    fn should_signal_eof_only_if_empty() {
+        use crossterm::event::KeyModifiers;
+        use Action::{Delete, Eof, Interrupt};
+        use KeyCode::Char;
+
+        let ctrl = |c| Event::new(Char(c), KeyModifiers::CONTROL);
+
+        assert_eq!(default_action(ctrl('d'), &Buffer::new()), Eof);
+        assert_eq!(
+            default_action(ctrl('d'), &Buffer::new_with_cursor("ab", 1).unwrap()),
+            Delete(super::Scope::Relative(Range::Single, Direction::Forward))
+        );
+        assert_eq!(default_action(ctrl('c'), &Buffer::new()), Interrupt);
+        assert_eq!(default_action(ctrl('c'), &"a".into()), Interrupt);
+    }
+
     #[test]
     fn should_default_if_no_mapping() {
         use super::KeyBindings;
         use KeyCode::Tab;
-        let action = action_for::<KeyBindings>(None, Event::from(Tab), &Buffer::new());
+        let action = action_for::<KeyBindings>(None, Event::from(Tab), &Buffer::new(), None, false);
         assert_eq!(action, Action::Suggest(Direction::Forward));
     }
 
-    mod basic {
-        use super::super::{
-            action_for, Action, Buffer, Direction, Event, KeyBindings, KeyCode::Tab,
+    #[test]
+    fn should_navigate_menu_if_displayed() {
+        use Action::{Complete, Move, Recall, SelectColumn, SelectRow};
+        use Direction::{Backward, Forward};
+        use KeyCode::{Down, Left, Right, Up};
+
+        let action = |code, menu| {
+            action_for::<super::KeyBindings>(None, Event::from(code), &"a".into(), menu, false)
+        };
+
+        assert_eq!(action(Up, None), Recall(Backward));
+        assert_eq!(action(Down, None), Recall(Forward));
+        assert_eq!(action(Left, None), Move(Range::Single, Backward));
+        assert_eq!(action(Right, None), Complete(Range::Line));
+
+        assert_eq!(action(Up, Some(Layout::List)), SelectRow(Backward));
+        assert_eq!(action(Down, Some(Layout::List)), SelectRow(Forward));
+        assert_eq!(
+            action(Left, Some(Layout::List)),
+            Move(Range::Single, Backward)
+        );
+        assert_eq!(action(Right, Some(Layout::List)), Complete(Range::Line));
+
+        assert_eq!(action(Up, Some(Layout::Grid)), SelectRow(Backward));
+        assert_eq!(action(Down, Some(Layout::Grid)), SelectRow(Forward));
+        assert_eq!(action(Left, Some(Layout::Grid)), SelectColumn(Backward));
+        assert_eq!(action(Right, Some(Layout::Grid)), SelectColumn(Forward));
+    }
+
+    #[test]
+    fn should_complete_in_place_if_completing_at_cursor() {
+        use crossterm::event::KeyModifiers;
+        use Action::{Complete, Move};
+        use Direction::{Backward, Forward};
+        use KeyCode::{Char, End, Left, Right};
+        use Range::{Line, Single, Word};
+
+        let buffer = Buffer::new_with_cursor("git chec --force", 8).unwrap();
+        let action = |event, completing| {
+            action_for::<super::KeyBindings>(None, event, &buffer, None, completing)
         };
 
+        assert_eq!(action(Event::from(Right), false), Move(Single, Forward));
+        assert_eq!(action(Event::from(Right), true), Complete(Line));
+        assert_eq!(action(Event::from(End), true), Complete(Line));
+        assert_eq!(
+            action(Event::new(Char('f'), KeyModifiers::ALT), true),
+            Complete(Word)
+        );
+        assert_eq!(action(Event::from(Left), true), Move(Single, Backward));
+    }
+
+    #[test]
+    fn should_take_every_action_of_an_editor_overrider() {
+        use super::actions_for;
+        use Action::{Accept, Move, Write};
+
+        struct CommentOut;
+
+        impl EditorOverrider for CommentOut {
+            fn override_with(&self, event: Event, editor: &mut dyn Editor) -> Option<Vec<Action>> {
+                if event == Event::from(KeyCode::F(2)) && !editor.buffer().is_empty() {
+                    Some(vec![
+                        Move(Range::Line, Direction::Backward),
+                        Write('#'),
+                        Accept,
+                    ])
+                } else {
+                    None
+                }
+            }
+        }
+
+        let mut editor = Line("ls".into());
+        assert_eq!(
+            actions_for(
+                Some(&CommentOut),
+                Event::from(KeyCode::F(2)),
+                &mut editor,
+                None,
+                false
+            ),
+            vec![Move(Range::Line, Direction::Backward), Write('#'), Accept]
+        );
+        assert_eq!(
+            actions_for(
+                Some(&CommentOut),
+                Event::from(KeyCode::Enter),
+                &mut editor,
+                None,
+                false
+            ),
+            vec![Accept]
+        );
+    }
+
+    mod basic {
+        use super::super::{Action, Buffer, Direction, Event, KeyBindings, KeyCode::Tab};
+        use super::action_for;
+
         #[test]
         fn should_default_if_event_missing_form_mapping() {
             let overrider = KeyBindings::new();
-            let action = action_for(Some(&overrider), Event::from(Tab), &Buffer::new());
+            let action = action_for(
+                Some(&overrider),
+                Event::from(Tab),
+                &Buffer::new(),
+                None,
+                false,
+            );
             assert_eq!(action, Action::Suggest(Direction::Forward));
         }
 
@@ -399,18 +835,31 @@ mod test {
         fn should_override_if_defined() {
             let mut bindings = KeyBindings::new();
             bindings.insert(Event::from(Tab), Action::Write('\t'));
-            let action = action_for(Some(&bindings), Event::from(Tab), &Buffer::new());
+            let action = action_for(
+                Some(&bindings),
+                Event::from(Tab),
+                &Buffer::new(),
+                None,
+                false,
+            );
             assert_eq!(action, Action::Write('\t'));
         }
     }
 
     mod closure {
-        use super::super::{action_for, Action, Buffer, Direction, Event, KeyCode::Tab};
+        use super::super::{Action, Buffer, Direction, Event, KeyCode::Tab};
+        use super::action_for;
 
         #[test]
         fn should_default_if_event_missing_form_mapping() {
             let overrider = |_, _: &Buffer| None;
-            let action = action_for(Some(&overrider), Event::from(Tab), &Buffer::new());
+            let action = action_for(
+                Some(&overrider),
+                Event::from(Tab),
+                &Buffer::new(),
+                None,
+                false,
+            );
             assert_eq!(action, Action::Suggest(Direction::Forward));
         }
 
@@ -423,7 +872,13 @@ mod test {
                     None
                 }
             };
-            let action = action_for(Some(&overrider), Event::from(Tab), &Buffer::new());
+            let action = action_for(
+                Some(&overrider),
+                Event::from(Tab),
+                &Buffer::new(),
+                None,
+                false,
+            );
             assert_eq!(action, Action::Write('\t'));
         }
     }
@@ -1,7 +1,10 @@
 mod navigation;
+mod tokens;
 
 use crate::actions::{Direction, Range, Scope};
 
+pub use tokens::Token;
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct InvalidIndex;
 
@@ -70,7 +73,7 @@ impl Buffer {
     /// [`Buffer`]: struct.Buffer.html
     pub fn new_with_cursor<S: AsRef<str>>(string: S, cursor: usize) -> Result<Self, InvalidIndex> {
         let mut buffer = Buffer::from(string);
-        buffer.set_cursor(cursor).map(|_| buffer)
+        buffer.set_cursor(cursor).map(|()| buffer)
     }
 
     /// Returns the current buffer string.
@@ -102,6 +105,39 @@ impl Buffer {
         }
     }
 
+    /// Splits the buffer into whitespace separated [`tokens`], as a shell would, honoring quotes
+    /// and backslash escapes.
+    ///
+    /// [`tokens`]: struct.Token.html
+    #[must_use]
+    pub fn tokens(&self) -> Vec<Token> {
+        tokens::tokenize(&self.string)
+    }
+
+    /// Returns the [`token`] under the cursor.
+    ///
+    /// If the cursor is touching a token, either inside of it or at one of its ends, that token
+    /// is returned. Otherwise, an empty token at the cursor position is returned, with the index
+    /// that a token typed there would have.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rucline::Buffer;
+    ///
+    /// let buffer = Buffer::new_with_cursor("git comm --all", 6).unwrap();
+    /// let token = buffer.current_token();
+    /// assert_eq!(token.index(), 1);
+    /// assert_eq!(token.span(), 4..8);
+    /// assert_eq!(token.text(), "comm");
+    /// ```
+    ///
+    /// [`token`]: struct.Token.html
+    #[must_use]
+    pub fn current_token(&self) -> Token {
+        tokens::token_at(&self.string, self.cursor)
+    }
+
     /// Puts the cursor at the end of the buffer.
     ///
     /// This is short-hand for `move_cursor(Range::Line, Direction::Forward)`
@@ -165,6 +201,29 @@ impl Buffer {
         }
     }
 
+    /// Replaces the byte range `span` of the buffer with `string` and puts the cursor at the end
+    /// of the inserted string. The rest of the buffer is left in place.
+    ///
+    /// # Arguments
+    /// * `span` - The byte range of the buffer to replace.
+    /// * `string` - The string to insert in its place.
+    ///
+    /// # Errors
+    /// * If the span is out of bounds or does not fall into character boundaries.
+    pub fn replace_range(
+        &mut self,
+        span: std::ops::Range<usize>,
+        string: &str,
+    ) -> Result<(), InvalidIndex> {
+        if self.string.get(span.clone()).is_none() {
+            return Err(InvalidIndex);
+        }
+
+        self.cursor = span.start + string.len();
+        self.string.replace_range(span, string);
+        Ok(())
+    }
+
     /// Deletes the given [`scope`] from this buffer and updates the cursor accordingly.
     ///
     /// # Arguments
@@ -675,6 +734,20 @@ mod test {
         );
     }
 
+    #[test]
+    fn replace_range_keeps_the_rest_of_the_line() {
+        let mut buffer = Buffer::new_with_cursor("git chec --force", 8).unwrap();
+
+        buffer.replace_range(4..8, "checkout").unwrap();
+        assert_eq!(buffer.as_str(), "git checkout --force");
+        assert_eq!(buffer.cursor(), 12);
+
+        assert!(buffer.replace_range(4..100, "x").is_err());
+        assert!(buffer.replace_range(5..5, "é").is_ok());
+        assert!(buffer.replace_range(6..7, "x").is_err());
+        assert_eq!(buffer.as_str(), "git céheckout --force");
+    }
+
     #[derive(Clone, Copy)]
     struct Jig {
         empty: &'static str,
@@ -20,9 +20,7 @@ pub(super) fn next_word(pivot: usize, string: &str) -> usize {
         pivot
     } else {
         unicode_segmentation::UnicodeSegmentation::split_word_bound_indices(string)
-            .find(|pair| {
-                pair.0 > pivot && pair.1.chars().next().map_or(true, |c| !c.is_whitespace())
-            })
+            .find(|pair| pair.0 > pivot && pair.1.chars().next().is_none_or(|c| !c.is_whitespace()))
             .map_or(string.len(), |pair| pair.0)
     }
 }
@@ -33,7 +31,7 @@ pub(super) fn previous_word(pivot: usize, string: &str) -> usize {
     } else {
         unicode_segmentation::UnicodeSegmentation::split_word_bound_indices(string)
             .rfind(|pair| {
-                pair.0 < pivot && pair.1.chars().next().map_or(true, |c| !c.is_whitespace())
+                pair.0 < pivot && pair.1.chars().next().is_none_or(|c| !c.is_whitespace())
             })
             .map_or(0, |pair| pair.0)
     }
@@ -46,7 +44,7 @@ pub(super) fn previous_word_end(pivot: usize, string: &str) -> usize {
         unicode_segmentation::UnicodeSegmentation::split_word_bound_indices(string)
             .rfind(|pair| {
                 pair.0 + pair.1.len() < pivot
-                    && pair.1.chars().next().map_or(true, |c| !c.is_whitespace())
+                    && pair.1.chars().next().is_none_or(|c| !c.is_whitespace())
             })
             .map_or(0, |pair| pair.0 + pair.1.len())
     }
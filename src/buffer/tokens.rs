@@ -0,0 +1,180 @@
+/// A whitespace separated argument of a [`Buffer`], as a shell would split it.
+///
+/// Whitespace inside of single or double quotes, or escaped with a backslash, does not split
+/// tokens. The [`text`] of the token has its quotes and escapes removed, while its [`span`] covers
+/// the token as written in the buffer.
+///
+/// # Example
+///
+/// ```
+/// use rucline::Buffer;
+///
+/// let buffer = Buffer::from(r#"cp my\ file "other dir""#);
+/// let tokens = buffer.tokens();
+///
+/// assert_eq!(tokens.len(), 3);
+/// assert_eq!(tokens[1].text(), "my file");
+/// assert_eq!(tokens[1].span(), 3..11);
+/// assert_eq!(tokens[2].text(), "other dir");
+/// assert_eq!(tokens[2].index(), 2);
+/// ```
+///
+/// [`Buffer`]: struct.Buffer.html
+/// [`span`]: struct.Token.html#method.span
+/// [`text`]: struct.Token.html#method.text
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Token {
+    index: usize,
+    span: std::ops::Range<usize>,
+    text: String,
+}
+
+impl Token {
+    /// Returns the position of this token among the tokens of the buffer, starting at zero.
+    #[inline]
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the byte range of the buffer covered by this token, including quotes and escapes.
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> std::ops::Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns the text of this token, with quotes and escapes removed.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+pub(super) fn tokenize(string: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut current: Option<Token> = None;
+    let mut quoted = None;
+    let mut escaped = false;
+
+    for (index, c) in string.char_indices() {
+        if current.is_none() && quoted.is_none() && !escaped && c.is_whitespace() {
+            continue;
+        }
+
+        let token = current.get_or_insert_with(|| Token {
+            index: tokens.len(),
+            span: index..index,
+            text: String::new(),
+        });
+
+        if escaped {
+            escaped = false;
+            token.text.push(c);
+        } else if c == '\\' && quoted != Some('\'') {
+            escaped = true;
+        } else if Some(c) == quoted {
+            quoted = None;
+        } else if quoted.is_none() && (c == '\'' || c == '"') {
+            quoted = Some(c);
+        } else if quoted.is_none() && c.is_whitespace() {
+            tokens.extend(current.take());
+            continue;
+        } else {
+            token.text.push(c);
+        }
+
+        token.span.end = index + c.len_utf8();
+    }
+
+    tokens.extend(current);
+    tokens
+}
+
+pub(super) fn token_at(string: &str, cursor: usize) -> Token {
+    let tokens = tokenize(string);
+    let index = tokens
+        .iter()
+        .take_while(|token| token.span.start <= cursor)
+        .count();
+
+    match index
+        .checked_sub(1)
+        .and_then(|last| tokens.into_iter().nth(last))
+    {
+        Some(token) if token.span.end >= cursor => token,
+        _ => Token {
+            index,
+            span: cursor..cursor,
+            text: String::new(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{token_at, tokenize};
+
+    fn texts(string: &str) -> Vec<String> {
+        tokenize(string)
+            .into_iter()
+            .map(|token| token.text)
+            .collect()
+    }
+
+    #[test]
+    fn split_on_whitespace() {
+        assert_eq!(texts("  git   commit\t-m  "), vec!["git", "commit", "-m"]);
+        assert!(texts("").is_empty());
+        assert!(texts(" \t ").is_empty());
+    }
+
+    #[test]
+    fn keep_quoted_and_escaped_whitespace() {
+        assert_eq!(
+            texts(r#"a\ b "c d" 'e f' g"h i"j"#),
+            vec!["a b", "c d", "e f", "gh ij"]
+        );
+        assert_eq!(texts(r#"'a\ b' "c\"d""#), vec![r"a\ b", "c\"d"]);
+    }
+
+    #[test]
+    fn unterminated_quote_runs_to_the_end() {
+        let tokens = tokenize("cat \"my fi");
+        assert_eq!(tokens[1].text, "my fi");
+        assert_eq!(tokens[1].span, 4..10);
+    }
+
+    #[test]
+    fn token_under_the_cursor() {
+        let string = "git commit  -m";
+
+        let token = token_at(string, 0);
+        assert_eq!(
+            (token.index, token.span, token.text.as_str()),
+            (0, 0..3, "git")
+        );
+
+        let token = token_at(string, 3);
+        assert_eq!(
+            (token.index, token.span, token.text.as_str()),
+            (0, 0..3, "git")
+        );
+
+        let token = token_at(string, 6);
+        assert_eq!((token.index, token.span), (1, 4..10));
+
+        let token = token_at(string, 11);
+        assert_eq!(
+            (token.index, token.span, token.text.as_str()),
+            (2, 11..11, "")
+        );
+
+        let token = token_at(string, string.len());
+        assert_eq!((token.index, token.span), (2, 12..14));
+
+        let token = token_at("git ", 4);
+        assert_eq!((token.index, token.span), (1, 4..4));
+    }
+}
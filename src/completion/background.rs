@@ -0,0 +1,260 @@
+use super::{Buffer, Completer, Completion, Suggester, Suggestion};
+
+use std::sync::{mpsc, Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+/// Runs a [`Completer`] or a [`Suggester`] on a background thread, so that slow sources, such as
+/// a database query, do not freeze typing.
+///
+/// When the buffer changes, a request is sent to a worker thread and nothing is completed until
+/// the result comes back. Requests are debounced, i.e. the worker waits for typing to pause before
+/// querying the wrapped source, and only the most recent buffer is queried. Results for a buffer
+/// that has since changed are discarded.
+///
+/// While a request is pending, the [`prompt`] keeps polling for the result and re-renders the line
+/// as soon as it arrives, even if no key was pressed.
+///
+/// # Example
+///
+/// ```no_run
+/// use rucline::completion::{Background, Buffer, Completer, Completion};
+/// use rucline::prompt::{Builder, Prompt};
+///
+/// struct Database;
+/// impl Completer for Database {
+///     fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+///         // Slow query
+/// #       None
+///     }
+/// }
+///
+/// let prompt = Prompt::new().completer(
+///     Background::new(Database).with_debounce(std::time::Duration::from_millis(100)),
+/// );
+/// ```
+///
+/// [`Completer`]: trait.Completer.html
+/// [`Suggester`]: trait.Suggester.html
+/// [`prompt`]: ../prompt/index.html
+pub struct Background<T> {
+    inner: Arc<T>,
+    debounce: Duration,
+    completions: OnceLock<Worker<Option<Completion<'static>>>>,
+    suggestions: OnceLock<Worker<Vec<Suggestion<'static>>>>,
+}
+
+impl<T> Background<T> {
+    /// Creates a new [`Background`] wrapping `inner`, with a debounce of 50 milliseconds.
+    ///
+    /// The worker thread is only started on the first request.
+    ///
+    /// [`Background`]: struct.Background.html
+    #[must_use]
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            debounce: Duration::from_millis(50),
+            completions: OnceLock::new(),
+            suggestions: OnceLock::new(),
+        }
+    }
+
+    /// Sets how long typing must pause before the wrapped source is queried.
+    ///
+    /// # Arguments
+    /// * `debounce` - The pause to wait for. Zero queries on every change.
+    #[must_use]
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+}
+
+impl<T> Completer for Background<T>
+where
+    T: Completer + Send + Sync + 'static,
+{
+    fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+        self.completions
+            .get_or_init(|| {
+                Worker::spawn(self.inner.clone(), self.debounce, |inner, buffer| {
+                    inner.complete_for(buffer).map(Completion::into_owned)
+                })
+            })
+            .fetch(buffer)
+            .flatten()
+    }
+
+    fn is_completion_pending(&self) -> bool {
+        self.completions.get().is_some_and(Worker::is_pending)
+    }
+}
+
+impl<T> Suggester for Background<T>
+where
+    T: Suggester + Send + Sync + 'static,
+{
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+        self.suggestions
+            .get_or_init(|| {
+                Worker::spawn(self.inner.clone(), self.debounce, |inner, buffer| {
+                    inner
+                        .suggest_for(buffer)
+                        .into_iter()
+                        .map(Suggestion::into_owned)
+                        .collect()
+                })
+            })
+            .fetch(buffer)
+            .unwrap_or_default()
+    }
+
+    fn is_suggestion_pending(&self) -> bool {
+        self.suggestions.get().is_some_and(Worker::is_pending)
+    }
+}
+
+struct Worker<R> {
+    requests: mpsc::Sender<Buffer>,
+    state: Arc<Mutex<State<R>>>,
+}
+
+struct State<R> {
+    // The buffer of the latest request, until its result comes back
+    requested: Option<Buffer>,
+    result: Option<(Buffer, R)>,
+}
+
+impl<R: Clone + Send + 'static> Worker<R> {
+    fn spawn<T, F>(inner: Arc<T>, debounce: Duration, query: F) -> Self
+    where
+        T: Send + Sync + 'static,
+        F: Fn(&T, &Buffer) -> R + Send + 'static,
+    {
+        let (requests, receiver) = mpsc::channel::<Buffer>();
+        let state = Arc::new(Mutex::new(State {
+            requested: None,
+            result: None,
+        }));
+
+        let shared = state.clone();
+        std::thread::spawn(move || {
+            while let Ok(mut buffer) = receiver.recv() {
+                // Wait for a pause, keeping only the latest request
+                loop {
+                    match receiver.recv_timeout(debounce) {
+                        Ok(newer) => buffer = newer,
+                        Err(mpsc::RecvTimeoutError::Timeout) => break,
+                        Err(mpsc::RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+
+                let result = query(&inner, &buffer);
+
+                let Ok(mut state) = shared.lock() else {
+                    return;
+                };
+                if state.requested.as_ref() == Some(&buffer) {
+                    state.requested = None;
+                    state.result = Some((buffer, result));
+                }
+            }
+        });
+
+        Self { requests, state }
+    }
+
+    // Returns the result for `buffer` if it is ready, otherwise requests it
+    fn fetch(&self, buffer: &Buffer) -> Option<R> {
+        let mut state = self.state.lock().ok()?;
+
+        if let Some((_, result)) = state.result.as_ref().filter(|(b, _)| b == buffer) {
+            return Some(result.clone());
+        }
+
+        if state.requested.as_ref() != Some(buffer) {
+            state.requested = Some(buffer.clone());
+            // The worker only stops when this sender is dropped
+            let _ = self.requests.send(buffer.clone());
+        }
+
+        None
+    }
+
+    fn is_pending(&self) -> bool {
+        self.state
+            .lock()
+            .is_ok_and(|state| state.requested.is_some())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Background, Buffer, Completer, Completion, Suggester};
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    struct Counting(Arc<AtomicUsize>);
+
+    impl Completer for Counting {
+        fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Some(format!("{}!", buffer.as_str()).into())
+        }
+    }
+
+    fn wait<T: Completer>(completer: &T) {
+        for _ in 0..200 {
+            if !completer.is_completion_pending() {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        panic!("background request did not finish");
+    }
+
+    #[test]
+    fn complete_once_the_result_is_back() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let completer = Background::new(Counting(calls.clone())).with_debounce(Duration::ZERO);
+        let buffer = Buffer::from("a");
+
+        assert!(completer.complete_for(&buffer).is_none());
+
+        wait(&completer);
+        assert_eq!(completer.complete_for(&buffer).unwrap(), "a!");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn debounce_to_the_latest_request() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let completer =
+            Background::new(Counting(calls.clone())).with_debounce(Duration::from_millis(50));
+
+        for text in ["a", "ab", "abc"] {
+            assert!(completer.complete_for(&text.into()).is_none());
+        }
+
+        wait(&completer);
+        assert_eq!(completer.complete_for(&"abc".into()).unwrap(), "abc!");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn suggest_in_the_background() {
+        let suggester = Background::new(vec!["a", "b"]).with_debounce(Duration::ZERO);
+        let buffer = Buffer::new();
+
+        assert!(suggester.suggest_for(&buffer).is_empty());
+        for _ in 0..200 {
+            if !suggester.is_suggestion_pending() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(suggester.suggest_for(&buffer), vec!["a", "b"]);
+    }
+}
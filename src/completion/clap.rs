@@ -0,0 +1,181 @@
+use super::{Buffer, Command, Flag, Paths, Suggester, Suggestion};
+
+/// Derives the command tree of a [`clap::Command`], including its subcommands, long and short
+/// flags, and positional arguments. Hidden commands and arguments are left out.
+///
+/// Values are suggested from the possible values of an argument, if any, or from the filesystem
+/// if its value hint is a path.
+///
+/// [`clap::Command`]: https://docs.rs/clap/latest/clap/struct.Command.html
+impl<'a> From<&'a ::clap::Command> for Command<'a> {
+    fn from(command: &'a ::clap::Command) -> Self {
+        let mut spec = Command::new(command.get_name());
+        if let Some(about) = command.get_about() {
+            spec = spec.with_description(about.to_string());
+        }
+
+        for argument in command.get_arguments().filter(|a| !a.is_hide_set()) {
+            if argument.is_positional() {
+                spec = spec.with_argument(Values::from(argument));
+            } else if let Some(flag) = flag(argument) {
+                spec = spec.with_flag(flag);
+            }
+        }
+
+        command
+            .get_subcommands()
+            .filter(|subcommand| !subcommand.is_hide_set())
+            .fold(spec, |spec, subcommand| {
+                spec.with_subcommand(Command::from(subcommand))
+            })
+    }
+}
+
+fn flag(argument: &::clap::Arg) -> Option<Flag<'_>> {
+    let mut flag = match (argument.get_long(), argument.get_short()) {
+        (Some(long), short) => {
+            let flag = Flag::long(long);
+            if let Some(short) = short {
+                flag.with_short(short)
+            } else {
+                flag
+            }
+        }
+        (None, Some(short)) => Flag::short(short),
+        (None, None) => return None,
+    };
+
+    if let Some(help) = argument.get_help() {
+        flag = flag.with_description(help.to_string());
+    }
+
+    if argument.get_action().takes_values() {
+        flag = flag.with_values(Values::from(argument));
+    }
+
+    Some(flag)
+}
+
+enum Values {
+    Possible(Vec<Suggestion<'static>>),
+    Paths(Paths),
+    None,
+}
+
+impl From<&::clap::Arg> for Values {
+    fn from(argument: &::clap::Arg) -> Self {
+        use ::clap::ValueHint::{AnyPath, DirPath, ExecutablePath, FilePath};
+
+        let possible = argument
+            .get_possible_values()
+            .into_iter()
+            .filter(|value| !value.is_hide_set())
+            .map(|value| {
+                let suggestion = Suggestion::new(value.get_name().to_owned());
+                if let Some(help) = value.get_help() {
+                    suggestion.with_description(help.to_string())
+                } else {
+                    suggestion
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if !possible.is_empty() {
+            Values::Possible(possible)
+        } else if matches!(
+            argument.get_value_hint(),
+            AnyPath | DirPath | ExecutablePath | FilePath
+        ) {
+            Values::Paths(Paths::new())
+        } else {
+            Values::None
+        }
+    }
+}
+
+impl Suggester for Values {
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+        match self {
+            Values::Possible(values) => values.clone(),
+            Values::Paths(paths) => paths.suggest_for(buffer),
+            Values::None => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Command, Suggester};
+    use ::clap::{Arg, ArgAction, ValueHint};
+
+    fn cli() -> ::clap::Command {
+        ::clap::Command::new("tool")
+            .arg(
+                Arg::new("verbose")
+                    .long("verbose")
+                    .short('v')
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(Arg::new("secret").long("secret").hide(true))
+            .subcommand(
+                ::clap::Command::new("build")
+                    .about("Build the project")
+                    .arg(
+                        Arg::new("profile")
+                            .long("profile")
+                            .value_parser(["debug", "release"]),
+                    )
+                    .arg(
+                        Arg::new("manifest")
+                            .long("manifest")
+                            .value_hint(ValueHint::FilePath),
+                    ),
+            )
+            .subcommand(
+                ::clap::Command::new("run").arg(Arg::new("target").value_parser(["app", "tests"])),
+            )
+    }
+
+    fn suggest(command: &Command<'_>, buffer: &str) -> Vec<String> {
+        command
+            .suggest_for(&buffer.into())
+            .into_iter()
+            .map(|suggestion| suggestion.replacement().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn derive_subcommands_and_flags() {
+        let cli = cli();
+        let command = Command::from(&cli);
+
+        assert_eq!(suggest(&command, ""), vec!["build", "run"]);
+        assert_eq!(suggest(&command, "-"), vec!["--verbose", "-v"]);
+        assert_eq!(
+            command.suggest_for(&"b".into())[0].description(),
+            Some("Build the project")
+        );
+    }
+
+    #[test]
+    fn derive_possible_values() {
+        let cli = cli();
+        let command = Command::from(&cli);
+
+        assert_eq!(
+            suggest(&command, "build --profile "),
+            vec!["debug", "release"]
+        );
+        assert_eq!(suggest(&command, "run t"), vec!["tests"]);
+    }
+
+    #[test]
+    fn derive_path_hints() {
+        let cli = cli();
+        let command = Command::from(&cli);
+
+        assert!(suggest(&command, "build --manifest Carg")
+            .iter()
+            .any(|path| path == "Cargo.toml"));
+    }
+}
@@ -0,0 +1,369 @@
+use super::{Buffer, Completer, Completion, Suggester, Suggestion};
+
+/// A [`Completer`] that falls back to a second completer.
+///
+/// The second completer is only asked for a completion when the first has none. It is pending
+/// while either of them is.
+///
+/// Created by [`Completer::or`].
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::{Completer, Or};
+///
+/// let completer: Or<Vec<&str>, Vec<&str>> = vec!["commit"].or(vec!["checkout"]);
+/// assert_eq!(completer.complete_for(&"co".into()).unwrap(), "mmit");
+/// assert_eq!(completer.complete_for(&"ch".into()).unwrap(), "eckout");
+/// ```
+///
+/// [`Completer`]: trait.Completer.html
+/// [`Completer::or`]: trait.Completer.html#method.or
+#[derive(Debug, Clone)]
+pub struct Or<A, B> {
+    pub(super) first: A,
+    pub(super) second: B,
+}
+
+impl<A: Completer, B: Completer> Completer for Or<A, B> {
+    fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+        self.first
+            .complete_for(buffer)
+            .or_else(|| self.second.complete_for(buffer))
+    }
+
+    fn is_completion_pending(&self) -> bool {
+        self.first.is_completion_pending() || self.second.is_completion_pending()
+    }
+}
+
+/// A [`Completer`] that transforms each completion of another completer.
+///
+/// Created by [`Completer::map_completion`].
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::{Completer, Completion};
+///
+/// let completer = vec!["commit"]
+///     .map_completion(|completion| Completion::new(completion.text().to_uppercase()));
+/// assert_eq!(completer.complete_for(&"co".into()).unwrap(), "MMIT");
+/// ```
+///
+/// [`Completer`]: trait.Completer.html
+/// [`Completer::map_completion`]: trait.Completer.html#method.map_completion
+#[derive(Debug, Clone)]
+pub struct MapCompletion<C, F> {
+    pub(super) completer: C,
+    pub(super) map: F,
+}
+
+impl<C, F> Completer for MapCompletion<C, F>
+where
+    C: Completer,
+    F: for<'c> Fn(Completion<'c>) -> Completion<'c>,
+{
+    fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+        self.completer.complete_for(buffer).map(&self.map)
+    }
+
+    fn is_completion_pending(&self) -> bool {
+        self.completer.is_completion_pending()
+    }
+}
+
+/// A [`Completer`] that drops the completions of another completer that do not match a
+/// predicate.
+///
+/// Created by [`Completer::filter_completion`].
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::Completer;
+///
+/// let completer = vec!["commit"].filter_completion(|completion| completion.text().len() > 3);
+/// assert_eq!(completer.complete_for(&"c".into()).unwrap(), "ommit");
+/// assert!(completer.complete_for(&"com".into()).is_none());
+/// ```
+///
+/// [`Completer`]: trait.Completer.html
+/// [`Completer::filter_completion`]: trait.Completer.html#method.filter_completion
+#[derive(Debug, Clone)]
+pub struct FilterCompletion<C, F> {
+    pub(super) completer: C,
+    pub(super) predicate: F,
+}
+
+impl<C, F> Completer for FilterCompletion<C, F>
+where
+    C: Completer,
+    F: Fn(&Completion<'_>) -> bool,
+{
+    fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+        self.completer
+            .complete_for(buffer)
+            .filter(|completion| (self.predicate)(completion))
+    }
+
+    fn is_completion_pending(&self) -> bool {
+        self.completer.is_completion_pending()
+    }
+}
+
+/// A [`Suggester`] that suggests the suggestions of one suggester followed by those of another.
+///
+/// It is pending while either of them is.
+///
+/// Created by [`Suggester::chain`].
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::Suggester;
+///
+/// let suggester = vec!["commit"].chain(vec!["checkout"]);
+/// assert_eq!(suggester.suggest_for(&"c".into()), vec!["commit", "checkout"]);
+/// ```
+///
+/// [`Suggester`]: trait.Suggester.html
+/// [`Suggester::chain`]: trait.Suggester.html#method.chain
+#[derive(Debug, Clone)]
+pub struct Chain<A, B> {
+    pub(super) first: A,
+    pub(super) second: B,
+}
+
+impl<A: Suggester, B: Suggester> Suggester for Chain<A, B> {
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+        let mut suggestions = self.first.suggest_for(buffer);
+        suggestions.extend(self.second.suggest_for(buffer));
+        suggestions
+    }
+
+    fn is_suggestion_pending(&self) -> bool {
+        self.first.is_suggestion_pending() || self.second.is_suggestion_pending()
+    }
+}
+
+/// A [`Suggester`] that transforms each suggestion of another suggester.
+///
+/// Created by [`Suggester::map_suggestion`].
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::Suggester;
+///
+/// let suggester = vec!["commit"].map_suggestion(|suggestion| suggestion.with_description("git"));
+/// assert_eq!(suggester.suggest_for(&"c".into())[0].description(), Some("git"));
+/// ```
+///
+/// [`Suggester`]: trait.Suggester.html
+/// [`Suggester::map_suggestion`]: trait.Suggester.html#method.map_suggestion
+#[derive(Debug, Clone)]
+pub struct MapSuggestion<S, F> {
+    pub(super) suggester: S,
+    pub(super) map: F,
+}
+
+impl<S, F> Suggester for MapSuggestion<S, F>
+where
+    S: Suggester,
+    F: for<'s> Fn(Suggestion<'s>) -> Suggestion<'s>,
+{
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+        self.suggester
+            .suggest_for(buffer)
+            .into_iter()
+            .map(&self.map)
+            .collect()
+    }
+
+    fn is_suggestion_pending(&self) -> bool {
+        self.suggester.is_suggestion_pending()
+    }
+}
+
+/// A [`Suggester`] that drops the suggestions of another suggester that do not match a
+/// predicate.
+///
+/// Created by [`Suggester::filter_suggestion`].
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::Suggester;
+///
+/// let suggester = vec!["commit", "checkout"]
+///     .filter_suggestion(|suggestion| suggestion.replacement() != "checkout");
+/// assert_eq!(suggester.suggest_for(&"c".into()), vec!["commit"]);
+/// ```
+///
+/// [`Suggester`]: trait.Suggester.html
+/// [`Suggester::filter_suggestion`]: trait.Suggester.html#method.filter_suggestion
+#[derive(Debug, Clone)]
+pub struct FilterSuggestion<S, F> {
+    pub(super) suggester: S,
+    pub(super) predicate: F,
+}
+
+impl<S, F> Suggester for FilterSuggestion<S, F>
+where
+    S: Suggester,
+    F: Fn(&Suggestion<'_>) -> bool,
+{
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+        let mut suggestions = self.suggester.suggest_for(buffer);
+        suggestions.retain(|suggestion| (self.predicate)(suggestion));
+        suggestions
+    }
+
+    fn is_suggestion_pending(&self) -> bool {
+        self.suggester.is_suggestion_pending()
+    }
+}
+
+/// A [`Suggester`] that drops repeated suggestions of another suggester.
+///
+/// Suggestions are repeated if they have the same replacement. The first one is kept.
+///
+/// Created by [`Suggester::unique`].
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::Suggester;
+///
+/// let suggester = vec!["commit"].chain(vec!["commit", "checkout"]).unique();
+/// assert_eq!(suggester.suggest_for(&"c".into()), vec!["commit", "checkout"]);
+/// ```
+///
+/// [`Suggester`]: trait.Suggester.html
+/// [`Suggester::unique`]: trait.Suggester.html#method.unique
+#[derive(Debug, Clone)]
+pub struct Unique<S> {
+    pub(super) suggester: S,
+}
+
+impl<S: Suggester> Suggester for Unique<S> {
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+        let mut seen = std::collections::HashSet::new();
+        let mut suggestions = self.suggester.suggest_for(buffer);
+        suggestions.retain(|suggestion| seen.insert(suggestion.replacement().to_owned()));
+        suggestions
+    }
+
+    fn is_suggestion_pending(&self) -> bool {
+        self.suggester.is_suggestion_pending()
+    }
+}
+
+/// A [`Suggester`] that keeps at most a number of suggestions of another suggester.
+///
+/// Created by [`Suggester::limit`].
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::Suggester;
+///
+/// let suggester = vec!["commit", "checkout", "cherry-pick"].limit(2);
+/// assert_eq!(suggester.suggest_for(&"c".into()), vec!["commit", "checkout"]);
+/// ```
+///
+/// [`Suggester`]: trait.Suggester.html
+/// [`Suggester::limit`]: trait.Suggester.html#method.limit
+#[derive(Debug, Clone)]
+pub struct Limit<S> {
+    pub(super) suggester: S,
+    pub(super) limit: usize,
+}
+
+impl<S: Suggester> Suggester for Limit<S> {
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+        let mut suggestions = self.suggester.suggest_for(buffer);
+        suggestions.truncate(self.limit);
+        suggestions
+    }
+
+    fn is_suggestion_pending(&self) -> bool {
+        self.suggester.is_suggestion_pending()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Buffer, Chain, Completer, Completion, Limit, Or, Suggester, Suggestion, Unique};
+
+    type Commands = Vec<&'static str>;
+
+    struct Completers {
+        completer: Or<Commands, Commands>,
+        suggester: Limit<Unique<Chain<Commands, Commands>>>,
+    }
+
+    #[test]
+    fn combinators_can_be_named() {
+        let completers = Completers {
+            completer: vec!["commit"].or(vec!["checkout"]),
+            suggester: vec!["commit"]
+                .chain(vec!["commit", "checkout"])
+                .unique()
+                .limit(1),
+        };
+        assert_eq!(
+            completers.completer.complete_for(&"ch".into()).unwrap(),
+            "eckout"
+        );
+        assert_eq!(
+            completers.suggester.suggest_for(&Buffer::new()),
+            vec!["commit"]
+        );
+    }
+
+    #[test]
+    fn or_falls_back_to_the_second_completer() {
+        let completer = vec!["abc"].or(vec!["xyz"]);
+        assert_eq!(completer.complete_for(&"a".into()).unwrap(), "bc");
+        assert_eq!(completer.complete_for(&"x".into()).unwrap(), "yz");
+        assert!(completer.complete_for(&"q".into()).is_none());
+    }
+
+    #[test]
+    fn map_and_filter_completions() {
+        let completer = vec!["abc", "xyz"]
+            .map_completion(|completion| Completion::new(completion.text().to_uppercase()))
+            .filter_completion(|completion| completion.text() != "YZ");
+        assert_eq!(completer.complete_for(&"a".into()).unwrap(), "BC");
+        assert!(completer.complete_for(&"x".into()).is_none());
+    }
+
+    #[test]
+    fn chain_unique_and_limit_suggestions() {
+        let suggester = vec!["a", "b"].chain(vec!["b", "c", "d"]);
+        assert_eq!(
+            suggester.suggest_for(&Buffer::new()),
+            vec!["a", "b", "b", "c", "d"]
+        );
+
+        let suggester = suggester.unique().limit(3);
+        assert_eq!(suggester.suggest_for(&Buffer::new()), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn map_and_filter_suggestions() {
+        let suggester = vec!["apple", "banana", "avocado"]
+            .filter_suggestion(|suggestion| suggestion.replacement().starts_with('a'))
+            .map_suggestion(|suggestion| suggestion.with_description("fruit"));
+
+        let suggestions = suggester.suggest_for(&Buffer::new());
+        assert_eq!(suggestions, vec!["apple", "avocado"]);
+        assert_eq!(suggestions[0].description(), Some("fruit"));
+        assert_eq!(
+            suggestions[1],
+            Suggestion::new("avocado").with_description("fruit")
+        );
+    }
+}
@@ -0,0 +1,480 @@
+use super::{Buffer, Completer, Completion, Suggester, Suggestion};
+
+use std::borrow::Cow;
+
+/// A declarative specification of a command line, completed and suggested from the tokens of
+/// the buffer.
+///
+/// A `Command` is made of nested subcommands, [`flags`] that may take values, and positional
+/// arguments, whose values are provided by any [`Suggester`], such as a list or [`Paths`].
+///
+/// The root command itself is not typed, i.e. the first token of the buffer is completed against
+/// its subcommands and arguments. This makes it a natural fit for REPLs. To complete the name of
+/// a program, add it as a subcommand of the root.
+///
+/// The tokens before the cursor are walked to find the command, flag or argument that is being
+/// typed. Options are only offered once a `-` is typed, and a `--` token ends the flags.
+///
+/// # Example
+///
+/// ```no_run
+/// use rucline::completion::{Command, Flag, Paths};
+/// use rucline::prompt::{Builder, Prompt};
+///
+/// let git = Command::new("git")
+///     .with_flag(Flag::long("version").with_description("Print the version"))
+///     .with_subcommand(
+///         Command::new("commit")
+///             .with_description("Record changes")
+///             .with_flag(Flag::long("all").with_short('a'))
+///             .with_flag(Flag::long("message").with_short('m').takes_value(true)),
+///     )
+///     .with_subcommand(
+///         Command::new("checkout")
+///             .with_description("Switch branches")
+///             .with_argument(vec!["main", "develop"]),
+///     )
+///     .with_subcommand(Command::new("add").with_argument(Paths::new()));
+///
+/// let prompt = Prompt::from("git> ").completer_ref(&git).suggester_ref(&git);
+/// ```
+///
+/// If the feature `clap` is enabled, a `Command` can also be derived from a [`clap::Command`],
+/// so that the command line interface and the prompt share the same definition:
+///
+/// ```no_run
+/// # #[cfg(feature = "clap")]
+/// # {
+/// use rucline::completion::Command;
+/// use rucline::prompt::{Builder, Prompt};
+///
+/// let cli = clap::Command::new("tool")
+///     .subcommand(clap::Command::new("build"))
+///     .subcommand(clap::Command::new("run"));
+/// let tool = Command::from(&cli);
+///
+/// let prompt = Prompt::from("tool> ").completer_ref(&tool).suggester_ref(&tool);
+/// # }
+/// ```
+///
+/// [`Paths`]: struct.Paths.html
+/// [`Suggester`]: trait.Suggester.html
+/// [`clap::Command`]: https://docs.rs/clap/latest/clap/struct.Command.html
+/// [`flags`]: struct.Flag.html
+pub struct Command<'a> {
+    name: Cow<'a, str>,
+    description: Option<Cow<'a, str>>,
+    subcommands: Vec<Command<'a>>,
+    flags: Vec<Flag<'a>>,
+    arguments: Vec<Box<dyn Suggester + 'a>>,
+}
+
+impl<'a> Command<'a> {
+    /// Creates a new [`Command`] with no subcommands, flags or arguments.
+    ///
+    /// # Arguments
+    /// * `name` - The name that is typed to invoke this command as a subcommand.
+    ///
+    /// [`Command`]: struct.Command.html
+    #[must_use]
+    pub fn new<S: Into<Cow<'a, str>>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            subcommands: Vec::new(),
+            flags: Vec::new(),
+            arguments: Vec::new(),
+        }
+    }
+
+    /// Sets the description shown next to this command when it is suggested.
+    ///
+    /// # Arguments
+    /// * `description` - The description of the command.
+    #[must_use]
+    pub fn with_description<S: Into<Cow<'a, str>>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Adds a subcommand.
+    ///
+    /// # Arguments
+    /// * `subcommand` - The subcommand to add.
+    #[must_use]
+    pub fn with_subcommand(mut self, subcommand: Command<'a>) -> Self {
+        self.subcommands.push(subcommand);
+        self
+    }
+
+    // Removes the subcommands with the given name
+    pub(crate) fn without_subcommand(mut self, name: &str) -> Self {
+        self.subcommands
+            .retain(|subcommand| subcommand.name != name);
+        self
+    }
+
+    /// Adds a [`flag`].
+    ///
+    /// # Arguments
+    /// * [`flag`] - The flag to add.
+    ///
+    /// [`flag`]: struct.Flag.html
+    #[must_use]
+    pub fn with_flag(mut self, flag: Flag<'a>) -> Self {
+        self.flags.push(flag);
+        self
+    }
+
+    /// Adds a positional argument, in order, with the [`Suggester`] of its values.
+    ///
+    /// # Arguments
+    /// * [`values`] - The suggester of the values for the argument.
+    ///
+    /// [`Suggester`]: trait.Suggester.html
+    /// [`values`]: trait.Suggester.html
+    #[must_use]
+    pub fn with_argument<S: Suggester + 'a>(mut self, values: S) -> Self {
+        self.arguments.push(Box::new(values));
+        self
+    }
+
+    /// Returns the name of this command.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of this command, if any.
+    #[inline]
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn suggestion(&self) -> Suggestion<'_> {
+        let suggestion = Suggestion::new(self.name.as_ref());
+        if let Some(description) = &self.description {
+            suggestion.with_description(description.as_ref())
+        } else {
+            suggestion
+        }
+    }
+
+    fn subcommand(&self, name: &str) -> Option<&Command<'a>> {
+        self.subcommands
+            .iter()
+            .find(|subcommand| subcommand.name == name)
+    }
+
+    fn flag(&self, token: &str) -> Option<&Flag<'a>> {
+        if let Some(long) = token.strip_prefix("--") {
+            self.flags
+                .iter()
+                .find(|flag| flag.long.as_deref() == Some(long))
+        } else {
+            // Only the last of a cluster of short flags, e.g. `-am`, may take a value
+            let short = token.strip_prefix('-')?.chars().next_back()?;
+            self.flags.iter().find(|flag| flag.short == Some(short))
+        }
+    }
+
+    fn candidates(&self, buffer: &Buffer) -> (std::ops::Range<usize>, Vec<Suggestion<'_>>) {
+        // Only the part of the token before the cursor is completed
+        let line = Buffer::from(&buffer[..buffer.cursor()]);
+        let token = line.current_token();
+        let span = token.span();
+        let typed = token.text();
+
+        let mut command = self;
+        let mut arguments = 0;
+        let mut value_for: Option<&Flag<'a>> = None;
+        let mut flags_ended = false;
+
+        for previous in line.tokens().iter().take(token.index()) {
+            let text = previous.text();
+            if value_for.take().is_some() {
+                continue;
+            }
+
+            if !flags_ended && text == "--" {
+                flags_ended = true;
+            } else if !flags_ended && text.len() > 1 && text.starts_with('-') {
+                value_for = command
+                    .flag(text)
+                    .filter(|flag| flag.takes_value && !text.contains('='));
+            } else if let Some(subcommand) = command.subcommand(text).filter(|_| arguments == 0) {
+                command = subcommand;
+            } else {
+                arguments += 1;
+            }
+        }
+
+        let candidates = if let Some(flag) = value_for {
+            flag.values
+                .as_ref()
+                .map(|values| values.suggest_for(&line))
+                .unwrap_or_default()
+        } else if !flags_ended && typed.starts_with('-') {
+            command.flags.iter().flat_map(Flag::candidates).collect()
+        } else {
+            let subcommands = command
+                .subcommands
+                .iter()
+                .filter(|_| arguments == 0)
+                .map(Command::suggestion);
+            let values = command
+                .arguments
+                .get(arguments)
+                .map(|values| values.suggest_for(&line))
+                .unwrap_or_default();
+            subcommands.chain(values).collect()
+        };
+
+        // Suggestions without a span of their own replace the token being typed
+        let candidates = candidates
+            .into_iter()
+            .filter_map(|candidate| {
+                if candidate.span().is_some() {
+                    Some(candidate)
+                } else if candidate.replacement().starts_with(typed) {
+                    Some(candidate.with_span(span.clone()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        (span, candidates)
+    }
+}
+
+impl Completer for Command<'_> {
+    fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+        let (span, candidates) = self.candidates(buffer);
+        if span.is_empty() {
+            return None;
+        }
+
+        let typed = &buffer[span];
+        candidates
+            .into_iter()
+            .find(|candidate| candidate.replacement().starts_with(typed))
+            .and_then(|candidate| {
+                let span = candidate.span()?;
+                Some(Completion::replacing(span, candidate.into_replacement()))
+            })
+    }
+}
+
+impl Suggester for Command<'_> {
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+        self.candidates(buffer).1
+    }
+}
+
+/// A flag of a [`Command`], with a long name such as `--message`, a short name such as `-m`, or
+/// both.
+///
+/// A flag may take a value, in which case the token that follows it is completed from the
+/// [`Suggester`] of its values, if any.
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::Flag;
+///
+/// let message = Flag::long("message").with_short('m').takes_value(true);
+/// let color = Flag::long("color").with_values(vec!["always", "auto", "never"]);
+/// let verbose = Flag::short('v').with_description("Be verbose");
+/// ```
+///
+/// [`Command`]: struct.Command.html
+/// [`Suggester`]: trait.Suggester.html
+pub struct Flag<'a> {
+    long: Option<Cow<'a, str>>,
+    short: Option<char>,
+    description: Option<Cow<'a, str>>,
+    takes_value: bool,
+    values: Option<Box<dyn Suggester + 'a>>,
+}
+
+impl<'a> Flag<'a> {
+    /// Creates a new [`Flag`] with a long name, typed as `--name`.
+    ///
+    /// # Arguments
+    /// * `name` - The long name, without the leading dashes.
+    ///
+    /// [`Flag`]: struct.Flag.html
+    #[must_use]
+    pub fn long<S: Into<Cow<'a, str>>>(name: S) -> Self {
+        Self {
+            long: Some(name.into()),
+            short: None,
+            description: None,
+            takes_value: false,
+            values: None,
+        }
+    }
+
+    /// Creates a new [`Flag`] with only a short name, typed as `-c`.
+    ///
+    /// # Arguments
+    /// * `name` - The short name, without the leading dash.
+    ///
+    /// [`Flag`]: struct.Flag.html
+    #[must_use]
+    pub fn short(name: char) -> Self {
+        Self {
+            long: None,
+            short: Some(name),
+            description: None,
+            takes_value: false,
+            values: None,
+        }
+    }
+
+    /// Sets the short name of this flag, typed as `-c`.
+    ///
+    /// # Arguments
+    /// * `name` - The short name, without the leading dash.
+    #[must_use]
+    pub fn with_short(mut self, name: char) -> Self {
+        self.short = Some(name);
+        self
+    }
+
+    /// Sets the description shown next to this flag when it is suggested.
+    ///
+    /// # Arguments
+    /// * `description` - The description of the flag.
+    #[must_use]
+    pub fn with_description<S: Into<Cow<'a, str>>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets whether this flag takes the token that follows it as its value.
+    ///
+    /// # Arguments
+    /// * `takes_value` - If the flag takes a value.
+    #[must_use]
+    pub fn takes_value(mut self, takes_value: bool) -> Self {
+        self.takes_value = takes_value;
+        self
+    }
+
+    /// Sets the [`Suggester`] of the values of this flag, which implies that it takes a value.
+    ///
+    /// # Arguments
+    /// * [`values`] - The suggester of the values for the flag.
+    ///
+    /// [`Suggester`]: trait.Suggester.html
+    /// [`values`]: trait.Suggester.html
+    #[must_use]
+    pub fn with_values<S: Suggester + 'a>(mut self, values: S) -> Self {
+        self.takes_value = true;
+        self.values = Some(Box::new(values));
+        self
+    }
+
+    fn candidates(&self) -> impl Iterator<Item = Suggestion<'_>> {
+        let long = self.long.as_ref().map(|long| format!("--{long}"));
+        let short = self.short.map(|short| format!("-{short}"));
+
+        long.into_iter().chain(short).map(move |name| {
+            let suggestion = Suggestion::new(name);
+            if let Some(description) = &self.description {
+                suggestion.with_description(description.as_ref())
+            } else {
+                suggestion
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Buffer, Command, Completer, Flag, Suggester};
+
+    fn git() -> Command<'static> {
+        Command::new("git")
+            .with_flag(Flag::long("version"))
+            .with_subcommand(
+                Command::new("commit")
+                    .with_description("Record changes")
+                    .with_flag(Flag::long("all").with_short('a'))
+                    .with_flag(
+                        Flag::long("message")
+                            .with_short('m')
+                            .with_values(vec!["fix", "feat"]),
+                    ),
+            )
+            .with_subcommand(
+                Command::new("checkout").with_argument(vec!["main", "develop", "feature"]),
+            )
+    }
+
+    fn suggest(command: &Command<'_>, buffer: &str) -> Vec<String> {
+        command
+            .suggest_for(&buffer.into())
+            .into_iter()
+            .map(|suggestion| suggestion.replacement().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn suggest_subcommands() {
+        let git = git();
+        assert_eq!(suggest(&git, ""), vec!["commit", "checkout"]);
+        assert_eq!(suggest(&git, "ch"), vec!["checkout"]);
+
+        let suggestions = git.suggest_for(&"c".into());
+        assert_eq!(suggestions[0].description(), Some("Record changes"));
+        assert_eq!(suggestions[0].span(), Some(0..1));
+    }
+
+    #[test]
+    fn suggest_flags_once_a_dash_is_typed() {
+        let git = git();
+        assert_eq!(suggest(&git, "-"), vec!["--version"]);
+        assert_eq!(
+            suggest(&git, "commit -"),
+            vec!["--all", "-a", "--message", "-m"]
+        );
+        assert_eq!(suggest(&git, "commit --m"), vec!["--message"]);
+        assert!(suggest(&git, "commit -- -").is_empty());
+    }
+
+    #[test]
+    fn suggest_flag_values() {
+        let git = git();
+        assert_eq!(suggest(&git, "commit -m "), vec!["fix", "feat"]);
+        assert_eq!(suggest(&git, "commit -am f"), vec!["fix", "feat"]);
+        assert_eq!(suggest(&git, "commit --message fe"), vec!["feat"]);
+        assert!(suggest(&git, "commit -m fix ").is_empty());
+    }
+
+    #[test]
+    fn suggest_positional_arguments() {
+        let git = git();
+        assert_eq!(
+            suggest(&git, "checkout "),
+            vec!["main", "develop", "feature"]
+        );
+        assert_eq!(suggest(&git, "checkout d"), vec!["develop"]);
+        assert!(suggest(&git, "checkout main ").is_empty());
+    }
+
+    #[test]
+    fn complete_the_current_token_in_place() {
+        let git = git();
+        let buffer = Buffer::new_with_cursor("commi -a", 5).unwrap();
+
+        let completion = git.complete_for(&buffer).unwrap();
+        assert_eq!(completion, "commit");
+        assert_eq!(completion.span(), Some(0..5));
+        assert!(git.complete_for(&"commit ".into()).is_none());
+    }
+}
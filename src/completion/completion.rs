@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+
+/// An in-line completion provided by a [`Completer`].
+///
+/// At its simplest, a `Completion` is the text to be **appended** to the end of the buffer.
+/// Strings convert into this kind of `Completion`.
+///
+/// A completion may instead **replace** a span of the buffer, e.g. the word under the cursor.
+/// In that case, the characters of the replacement that go beyond the replaced span are rendered
+/// after the span, and accepting the completion replaces the span in place, without moving the
+/// rest of the line.
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::Completion;
+///
+/// // Appends " --force" to the buffer
+/// let append = Completion::from(" --force");
+/// assert_eq!(append.text(), " --force");
+/// assert_eq!(append.span(), None);
+///
+/// // Replaces "chec" with "checkout" in "git chec --force"
+/// let replace = Completion::replacing(4..8, "checkout");
+/// assert_eq!(replace.text(), "checkout");
+/// assert_eq!(replace.span(), Some(4..8));
+/// ```
+///
+/// [`Completer`]: trait.Completer.html
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Completion<'a> {
+    text: Cow<'a, str>,
+    span: Option<std::ops::Range<usize>>,
+}
+
+impl<'a> Completion<'a> {
+    /// Creates a new [`Completion`] that appends `text` to the end of the buffer.
+    ///
+    /// [`Completion`]: struct.Completion.html
+    #[must_use]
+    pub fn new<S: Into<Cow<'a, str>>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            span: None,
+        }
+    }
+
+    /// Creates a new [`Completion`] that replaces the `span` of the buffer with `text`.
+    ///
+    /// # Arguments
+    /// * `span` - The byte range of the buffer to be replaced.
+    /// * `text` - The text that replaces the span.
+    ///
+    /// [`Completion`]: struct.Completion.html
+    #[must_use]
+    pub fn replacing<S: Into<Cow<'a, str>>>(span: std::ops::Range<usize>, text: S) -> Self {
+        Self {
+            text: text.into(),
+            span: Some(span),
+        }
+    }
+
+    /// Returns the completion text.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the byte range of the buffer replaced by this completion, or `None` if the
+    /// completion is appended to the buffer.
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> Option<std::ops::Range<usize>> {
+        self.span.clone()
+    }
+
+    /// Converts this completion into one that owns its text.
+    #[must_use]
+    pub fn into_owned(self) -> Completion<'static> {
+        Completion {
+            text: Cow::Owned(self.text.into_owned()),
+            span: self.span,
+        }
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for Completion<'a> {
+    fn from(text: Cow<'a, str>) -> Self {
+        Self::new(text)
+    }
+}
+
+impl<'a> From<&'a str> for Completion<'a> {
+    fn from(text: &'a str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl<'a> From<&'a String> for Completion<'a> {
+    fn from(text: &'a String) -> Self {
+        Self::new(text.as_str())
+    }
+}
+
+impl From<String> for Completion<'_> {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl PartialEq<str> for Completion<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.text == other
+    }
+}
+
+impl PartialEq<&str> for Completion<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.text == *other
+    }
+}
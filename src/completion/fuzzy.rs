@@ -0,0 +1,251 @@
+use super::{Buffer, Completer, Completion, Suggester, Suggestion};
+
+const MATCH: usize = 16;
+const CONSECUTIVE: usize = 8;
+const START: usize = 12;
+const BOUNDARY: usize = 8;
+
+/// Fuzzy matching adapter for a list of candidates.
+///
+/// A candidate matches if all the characters typed appear in it in the same order, ignoring case,
+/// even if there are other characters in between. For instance, `gco` matches `git checkout`.
+/// Matches are ranked by how closely they follow what was typed: consecutive characters and
+/// characters at the start of words score higher, while gaps score lower.
+///
+/// As a [`Suggester`], the candidates that match the buffer are suggested from best to worst
+/// match, with the matched characters highlighted. An empty buffer suggests all candidates.
+///
+/// As a [`Completer`], since in-line completions can only be appended to the buffer, the best
+/// ranked candidate that starts with the buffer is completed.
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::{Fuzzy, Suggester};
+///
+/// let commands = Fuzzy::new(vec!["git checkout", "git commit", "cargo check"]);
+///
+/// let suggestions = commands.suggest_for(&"gco".into());
+/// assert_eq!(suggestions.len(), 2);
+/// assert_eq!(suggestions[0], "git commit");
+/// assert_eq!(suggestions[1], "git checkout");
+/// ```
+///
+/// [`Completer`]: trait.Completer.html
+/// [`Suggester`]: trait.Suggester.html
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Fuzzy<S> {
+    candidates: Vec<S>,
+}
+
+impl<S: AsRef<str>> Fuzzy<S> {
+    /// Creates a new fuzzy adapter from any collection of `candidates`.
+    pub fn new<I: IntoIterator<Item = S>>(candidates: I) -> Self {
+        Self {
+            candidates: candidates.into_iter().collect(),
+        }
+    }
+
+    fn ranked(&self, query: &str) -> Vec<(&S, Match)> {
+        let mut ranked = self
+            .candidates
+            .iter()
+            .filter_map(|candidate| fuzzy_match(query, candidate.as_ref()).map(|m| (candidate, m)))
+            .collect::<Vec<_>>();
+
+        ranked.sort_by(|a, b| {
+            b.1.score
+                .cmp(&a.1.score)
+                .then_with(|| a.0.as_ref().len().cmp(&b.0.as_ref().len()))
+        });
+
+        ranked
+    }
+}
+
+impl<S: AsRef<str>> std::iter::FromIterator<S> for Fuzzy<S> {
+    fn from_iter<I: IntoIterator<Item = S>>(candidates: I) -> Self {
+        Self::new(candidates)
+    }
+}
+
+impl<S: AsRef<str>> Completer for Fuzzy<S> {
+    fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+        if buffer.is_empty() {
+            None
+        } else {
+            self.ranked(buffer)
+                .into_iter()
+                .find(|(candidate, _)| candidate.as_ref().starts_with(buffer.as_str()))
+                .map(|(candidate, _)| candidate.as_ref()[buffer.len()..].into())
+        }
+    }
+}
+
+impl<S: AsRef<str>> Suggester for Fuzzy<S> {
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+        if buffer.is_empty() {
+            self.candidates
+                .iter()
+                .map(|candidate| candidate.as_ref().into())
+                .collect()
+        } else {
+            self.ranked(buffer)
+                .into_iter()
+                .map(|(candidate, m)| {
+                    Suggestion::new(candidate.as_ref()).with_highlights(m.positions)
+                })
+                .collect()
+        }
+    }
+}
+
+/// The result of fuzzy matching a query against a candidate.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Match {
+    /// How good the match is. Higher is better.
+    pub(crate) score: usize,
+    /// Byte indices of the matched characters in the candidate.
+    pub(crate) positions: Vec<usize>,
+}
+
+/// Matches `query` as a case-insensitive subsequence of `candidate`.
+///
+/// The leftmost match is found first and then tightened by matching backwards from its end, so
+/// that `ab` matches the last two characters of `a_ab`.
+pub(crate) fn fuzzy_match(query: &str, candidate: &str) -> Option<Match> {
+    let mut query_chars = query.chars().peekable();
+    let mut end = None;
+    for (index, c) in candidate.char_indices() {
+        match query_chars.peek() {
+            Some(&q) if same_char(q, c) => {
+                query_chars.next();
+                end = Some(index + c.len_utf8());
+            }
+            Some(_) => {}
+            None => break,
+        }
+    }
+
+    if query_chars.next().is_some() {
+        return None;
+    }
+
+    let mut positions = Vec::with_capacity(query.len());
+    let mut query_chars = query.chars().rev().peekable();
+    for (index, c) in candidate[..end.unwrap_or(0)].char_indices().rev() {
+        match query_chars.peek() {
+            Some(&q) if same_char(q, c) => {
+                query_chars.next();
+                positions.push(index);
+            }
+            Some(_) => {}
+            None => break,
+        }
+    }
+    positions.reverse();
+
+    Some(Match {
+        score: score(candidate, &positions),
+        positions,
+    })
+}
+
+fn same_char(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+fn score(candidate: &str, positions: &[usize]) -> usize {
+    let mut score = 0;
+    let mut gaps = 0;
+    let mut previous_end = None;
+
+    for &position in positions {
+        let current = candidate[position..].chars().next().unwrap_or_default();
+        score += MATCH;
+
+        match candidate[..position].chars().next_back() {
+            None => score += START,
+            Some(previous) if !previous.is_alphanumeric() => score += BOUNDARY,
+            Some(previous) if previous.is_lowercase() && current.is_uppercase() => {
+                score += BOUNDARY;
+            }
+            Some(_) => {}
+        }
+
+        if let Some(end) = previous_end {
+            if end == position {
+                score += CONSECUTIVE;
+            } else {
+                gaps += candidate[end..position].chars().count();
+            }
+        }
+        previous_end = Some(position + current.len_utf8());
+    }
+
+    score.saturating_sub(gaps)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{fuzzy_match, Buffer, Completer, Fuzzy, Suggester};
+
+    #[test]
+    fn match_subsequence_ignoring_case() {
+        assert!(fuzzy_match("gco", "git checkout").is_some());
+        assert!(fuzzy_match("GCO", "git checkout").is_some());
+        assert!(fuzzy_match("gco", "Git CheckOut").is_some());
+        assert!(fuzzy_match("ogc", "git checkout").is_none());
+        assert!(fuzzy_match("gcox", "git checkout").is_none());
+    }
+
+    #[test]
+    fn match_positions_are_tight() {
+        let m = fuzzy_match("ab", "a_ab").unwrap();
+        assert_eq!(m.positions, vec![2, 3]);
+
+        let m = fuzzy_match("gco", "git checkout").unwrap();
+        assert_eq!(m.positions, vec![0, 7, 9]);
+    }
+
+    // Allowed because it makes test clearer
+    #[allow(clippy::non_ascii_literal)]
+    #[test]
+    fn match_positions_are_byte_indices() {
+        let m = fuzzy_match("éb", "aébc").unwrap();
+        assert_eq!(m.positions, vec![1, 3]);
+    }
+
+    #[test]
+    fn consecutive_and_boundary_matches_score_higher() {
+        let score = |query, candidate| fuzzy_match(query, candidate).unwrap().score;
+
+        assert!(score("che", "checkout") > score("che", "cache"));
+        assert!(score("co", "git commit") > score("co", "git checkout"));
+        assert!(score("gc", "git commit") > score("gc", "organic"));
+        assert!(score("fb", "fooBar") > score("fb", "foobar"));
+    }
+
+    #[test]
+    fn suggest_all_if_empty() {
+        let fuzzy = Fuzzy::new(["b", "a", "c"]);
+        assert_eq!(fuzzy.suggest_for(&Buffer::new()), vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn suggest_ranked_matches() {
+        let fuzzy = Fuzzy::new(["cargo check", "git checkout", "git commit", "gist"]);
+        let suggestions = fuzzy.suggest_for(&"gco".into());
+        assert_eq!(suggestions, vec!["git commit", "git checkout"]);
+        assert_eq!(suggestions[0].highlights(), &[0, 4, 5]);
+    }
+
+    #[test]
+    fn complete_the_best_prefixed_match() {
+        let fuzzy = Fuzzy::new(vec!["git commit --amend", "gco", "git commit"]);
+        assert_eq!(fuzzy.complete_for(&"git c".into()).unwrap(), "ommit");
+        assert_eq!(fuzzy.complete_for(&"gc".into()).unwrap(), "o");
+        assert_eq!(fuzzy.complete_for(&"gm".into()), None);
+        assert_eq!(fuzzy.complete_for(&Buffer::new()), None);
+    }
+}
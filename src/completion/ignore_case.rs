@@ -0,0 +1,126 @@
+use super::{Buffer, Completer, Completion, Suggester, Suggestion};
+
+/// Case-insensitive adapter for a list of candidates.
+///
+/// A candidate matches if it starts with the buffer, ignoring case. For instance, `gi` matches
+/// `Git`.
+///
+/// As a [`Completer`], the first matching candidate is completed. If the buffer differs from the
+/// candidate in case, the completion replaces the whole buffer, so that accepting it also fixes
+/// the case of what was already typed.
+///
+/// As a [`Suggester`], the matching candidates are suggested in order. An empty buffer suggests
+/// all candidates.
+///
+/// # Example
+///
+/// ```
+/// use rucline::completion::{Completer, IgnoreCase};
+///
+/// let commands = IgnoreCase::new(vec!["Git", "Cargo"]);
+///
+/// let completion = commands.complete_for(&"gi".into()).unwrap();
+/// assert_eq!(completion.text(), "Git");
+/// assert_eq!(completion.span(), Some(0..2));
+/// ```
+///
+/// [`Completer`]: trait.Completer.html
+/// [`Suggester`]: trait.Suggester.html
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct IgnoreCase<S> {
+    candidates: Vec<S>,
+}
+
+impl<S: AsRef<str>> IgnoreCase<S> {
+    /// Creates a new case-insensitive adapter from any collection of `candidates`.
+    pub fn new<I: IntoIterator<Item = S>>(candidates: I) -> Self {
+        Self {
+            candidates: candidates.into_iter().collect(),
+        }
+    }
+
+    fn matches(&self, prefix: &str) -> Vec<&str> {
+        self.candidates
+            .iter()
+            .map(AsRef::as_ref)
+            .filter(|candidate| starts_with_ignore_case(candidate, prefix))
+            .collect()
+    }
+}
+
+impl<S: AsRef<str>> std::iter::FromIterator<S> for IgnoreCase<S> {
+    fn from_iter<I: IntoIterator<Item = S>>(candidates: I) -> Self {
+        Self::new(candidates)
+    }
+}
+
+impl<S: AsRef<str>> Completer for IgnoreCase<S> {
+    fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+        if buffer.is_empty() {
+            return None;
+        }
+
+        self.matches(buffer).first().map(|&candidate| {
+            if let Some(remainder) = candidate.strip_prefix(buffer.as_str()) {
+                remainder.into()
+            } else {
+                Completion::replacing(0..buffer.len(), candidate)
+            }
+        })
+    }
+}
+
+impl<S: AsRef<str>> Suggester for IgnoreCase<S> {
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+        self.matches(buffer).into_iter().map(Into::into).collect()
+    }
+}
+
+pub(crate) fn starts_with_ignore_case(string: &str, prefix: &str) -> bool {
+    let mut string = string.chars().flat_map(char::to_lowercase);
+    prefix
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|c| string.next() == Some(c))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{starts_with_ignore_case, Buffer, Completer, Completion, IgnoreCase, Suggester};
+
+    #[test]
+    fn match_prefix_ignoring_case() {
+        assert!(starts_with_ignore_case("Git", "gi"));
+        assert!(starts_with_ignore_case("git", "GIT"));
+        assert!(starts_with_ignore_case("git", ""));
+        assert!(!starts_with_ignore_case("git", "gits"));
+        assert!(!starts_with_ignore_case("cargo", "g"));
+    }
+
+    #[test]
+    fn append_if_the_case_matches() {
+        let list = IgnoreCase::new(["Git", "Cargo"]);
+        assert_eq!(list.complete_for(&"Gi".into()), Some(Completion::from("t")));
+        assert_eq!(list.complete_for(&Buffer::new()), None);
+        assert_eq!(list.complete_for(&"x".into()), None);
+    }
+
+    #[test]
+    fn replace_the_buffer_to_fix_the_case() {
+        let list = IgnoreCase::new(["Git", "Cargo"]);
+        assert_eq!(
+            list.complete_for(&"cAr".into()),
+            Some(Completion::replacing(0..3, "Cargo"))
+        );
+    }
+
+    #[test]
+    fn suggest_the_matches() {
+        let list = IgnoreCase::new(["Git", "gist", "Cargo"]);
+        assert_eq!(list.suggest_for(&"GI".into()), vec!["Git", "gist"]);
+        assert_eq!(
+            list.suggest_for(&Buffer::new()),
+            vec!["Git", "gist", "Cargo"]
+        );
+    }
+}
@@ -0,0 +1,555 @@
+//! Provides completion methods for [`prompt`] when reading lines.
+//!
+//! By default, no completions are performed upon user interaction. However, if a [`Completer`]
+//! or a [`Suggester`] are provided, the [`prompt`] will query for completions for the current
+//! state of the line.
+//!
+//! This module also includes a convenience wrapper for lists, allowing quick implementation
+//! of completions, as well as built-in completions for filesystem paths and command lines.
+//!
+//! # Examples
+//!
+//! Basic implementation for in-line completion:
+//!
+//! ```no_run
+//! use rucline::Buffer;
+//! use rucline::completion::{Completer, Completion};
+//!
+//! struct Basic(Vec<String>);
+//! impl Completer for Basic {
+//!   fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+//!       if buffer.is_empty() {
+//!           None
+//!       } else {
+//!           self.0
+//!               .iter()
+//!               .find(|completion| completion.starts_with(buffer.as_str()))
+//!               .map(|completion| (&completion[buffer.len()..]).into())
+//!       }
+//!   }
+//! }
+//! ```
+//!
+//! In-line completion of the [`token`] under the cursor, leaving the rest of the line in place:
+//!
+//! ```no_run
+//! use rucline::Buffer;
+//! use rucline::completion::{Completer, Completion};
+//!
+//! struct Words(Vec<&'static str>);
+//! impl Completer for Words {
+//!   fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+//!       let token = buffer.current_token();
+//!       if token.text().is_empty() {
+//!           None
+//!       } else {
+//!           self.0
+//!               .iter()
+//!               .find(|candidate| candidate.starts_with(token.text()))
+//!               .map(|candidate| Completion::replacing(token.span(), *candidate))
+//!       }
+//!   }
+//! }
+//! ```
+//!
+//! Basic implementation for drop-down suggestions:
+//!
+//! ```no_run
+//! use rucline::Buffer;
+//! use rucline::completion::{Suggester, Suggestion};
+//!
+//! struct Basic(Vec<String>);
+//! impl Suggester for Basic {
+//!   fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+//!       self.0.iter().map(|suggestion| suggestion.into()).collect()
+//!   }
+//! }
+//! ```
+//!
+//! Suggestions with a description:
+//!
+//! ```no_run
+//! use rucline::Buffer;
+//! use rucline::completion::{Suggester, Suggestion};
+//!
+//! struct Commands(Vec<(&'static str, &'static str)>);
+//! impl Suggester for Commands {
+//!   fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+//!       self.0
+//!           .iter()
+//!           .map(|(name, description)| Suggestion::new(*name).with_description(*description))
+//!           .collect()
+//!   }
+//! }
+//! ```
+//!
+//! Basic implementation for drop-down suggestions with list:
+//!
+//! ```no_run
+//! use rucline::completion::Completer;
+//!
+//! let completions = vec!["abc", "def", "example word", "weird \"˚∆˙\""];
+//! let completer: &dyn Completer = &completions;
+//! ```
+//!
+//! Slow completions, such as database queries, can be run on a background thread with
+//! [`Background`]:
+//!
+//! ```no_run
+//! use rucline::completion::{Background, Completer};
+//!
+//! let completer: &dyn Completer = &Background::new(vec!["abc", "def"]);
+//! ```
+//!
+//! Fuzzy matching can be added to any list of candidates with [`Fuzzy`]:
+//!
+//! ```no_run
+//! use rucline::completion::{Fuzzy, Suggester};
+//!
+//! let suggester: &dyn Suggester = &Fuzzy::new(vec!["git checkout", "git commit"]);
+//! ```
+//!
+//! Case-insensitive matching, which also fixes the case of what was typed, can be added to any list
+//! of candidates with [`IgnoreCase`]:
+//!
+//! ```no_run
+//! use rucline::completion::{Completer, IgnoreCase};
+//!
+//! let completer: &dyn Completer = &IgnoreCase::new(vec!["Git", "Cargo"]);
+//! ```
+//!
+//! Filesystem paths can be completed and suggested for the token under the cursor with [`Paths`]:
+//!
+//! ```no_run
+//! use rucline::completion::{Paths, Suggester};
+//!
+//! let suggester: &dyn Suggester = &Paths::new().hide_dotfiles(true);
+//! ```
+//!
+//! Completers and suggesters can be combined without writing a custom type:
+//!
+//! ```no_run
+//! use rucline::completion::{Completer, Paths, Suggester};
+//!
+//! let completer = vec!["exit", "help"].or(Paths::new());
+//! let suggester = vec!["exit", "help"].chain(Paths::new()).unique().limit(10);
+//! ```
+//!
+//! Command lines with subcommands, flags and arguments can be declared with [`Command`]:
+//!
+//! ```no_run
+//! use rucline::completion::{Command, Flag, Paths, Suggester};
+//!
+//! let suggester: &dyn Suggester = &Command::new("cargo")
+//!     .with_subcommand(Command::new("build").with_flag(Flag::long("release")))
+//!     .with_subcommand(Command::new("add").with_argument(vec!["serde", "rand"]))
+//!     .with_subcommand(Command::new("run").with_flag(Flag::long("manifest-path").with_values(Paths::new())));
+//! ```
+//!
+//! # See also
+//! * [`Actions`]
+//!
+//! [`Actions`]: ../actions/index.html
+//! [`Background`]: struct.Background.html
+//! [`Command`]: struct.Command.html
+//! [`Completer`]: trait.Completer.html
+//! [`Completion`]: struct.Completion.html
+//! [`Fuzzy`]: struct.Fuzzy.html
+//! [`IgnoreCase`]: struct.IgnoreCase.html
+//! [`Paths`]: struct.Paths.html
+//! [`Suggester`]: trait.Suggester.html
+//! [`prompt`]: ../prompt/index.html
+//! [`token`]: ../struct.Token.html
+
+mod background;
+#[cfg(feature = "clap")]
+mod clap;
+mod combinators;
+mod command;
+// Allowed because `Completion` mirrors `Suggestion` in its own module
+#[allow(clippy::module_inception)]
+mod completion;
+mod fuzzy;
+mod ignore_case;
+mod paths;
+mod suggestion;
+
+pub use crate::Buffer;
+pub use background::Background;
+pub use combinators::{
+    Chain, FilterCompletion, FilterSuggestion, Limit, MapCompletion, MapSuggestion, Or, Unique,
+};
+pub use command::{Command, Flag};
+pub use completion::Completion;
+pub(crate) use fuzzy::fuzzy_match;
+pub use fuzzy::Fuzzy;
+pub(crate) use ignore_case::starts_with_ignore_case;
+pub use ignore_case::IgnoreCase;
+pub use paths::Paths;
+pub use suggestion::Suggestion;
+
+/// Completes the buffer in-line.
+///
+/// Whenever the line is edited, e.g. [`Write`] or [`Delete`], the [`prompt`] will ask the
+/// `Completer` for a possible [`Completion`] to **append** to the current buffer, or to
+/// **replace** a span of it, such as the word under the cursor. The implementation may use the
+/// [`Buffer`] to decide which completions are applicable.
+///
+/// When the `Completer` is invoked, the buffer is not actually changed, the completion is
+/// only rendered. A [`Complete`] action must be issued to incorporate the completion into
+/// the buffer.
+///
+/// # Example
+///
+/// Basic implementation:
+///
+/// ```no_run
+/// use rucline::Buffer;
+/// use rucline::completion::{Completer, Completion};
+///
+/// struct Basic(Vec<String>);
+/// impl Completer for Basic {
+///   fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+///       if buffer.is_empty() {
+///           None
+///       } else {
+///           self.0
+///               .iter()
+///               .find(|completion| completion.starts_with(buffer.as_str()))
+///               .map(|completion| (&completion[buffer.len()..]).into())
+///       }
+///   }
+/// }
+/// ```
+///
+/// # See also
+/// * [`Suggester`]
+///
+/// [`Buffer`]: ../buffer/struct.Buffer.html
+/// [`Complete`]: ../actions/enum.Action.html#variant.Complete
+/// [`Completion`]: struct.Completion.html
+/// [`Delete`]: ../actions/enum.Action.html#variant.Delete
+/// [`Suggester`]: trait.Suggester.html
+/// [`Write`]: ../actions/enum.Action.html#variant.Write
+/// [`prompt`]: ../prompt/index.html
+pub trait Completer {
+    /// Provides the in-line completion.
+    ///
+    /// Whenever the line is edited, e.g. [`Write`] or [`Delete`], the [`prompt`] will call
+    /// `complete_for` for a possible completion to **append** to the current buffer, or to
+    /// **replace** a span of it.
+    ///
+    /// # Arguments
+    /// * [`buffer`] - Read-only view into the line buffer, providing the context in which this
+    ///   event is happening.
+    ///
+    /// # Return
+    /// * A [`completion`] to be rendered. `None` if there are no suggestions.
+    ///
+    /// # See also
+    /// * [`Suggester`]
+    ///
+    /// [`Buffer`]: ../buffer/struct.Buffer.html
+    /// [`completion`]: struct.Completion.html
+    /// [`Delete`]: ../actions/enum.Action.html#variant.Delete
+    /// [`Suggester`]: trait.Suggester.html
+    /// [`Write`]: ../actions/enum.Action.html#variant.Write
+    /// [`prompt`]: ../prompt/index.html
+    fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>>;
+
+    /// Returns `true` while a completion is being computed elsewhere, e.g. in the [`Background`].
+    ///
+    /// While pending, the [`prompt`] keeps polling this method and calls [`complete_for`] again
+    /// once it returns `false`, so that the result is rendered without waiting for a key press.
+    ///
+    /// [`Background`]: struct.Background.html
+    /// [`complete_for`]: trait.Completer.html#tymethod.complete_for
+    /// [`prompt`]: ../prompt/index.html
+    fn is_completion_pending(&self) -> bool {
+        false
+    }
+
+    /// Combines this completer with `other`, which is only asked for a completion when this one
+    /// has none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rucline::completion::Completer;
+    ///
+    /// let completer = vec!["commit"].or(vec!["checkout"]);
+    /// assert_eq!(completer.complete_for(&"ch".into()).unwrap(), "eckout");
+    /// ```
+    #[must_use]
+    fn or<C: Completer>(self, other: C) -> Or<Self, C>
+    where
+        Self: Sized,
+    {
+        Or {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Transforms each completion of this completer with `map`.
+    #[must_use]
+    fn map_completion<F>(self, map: F) -> MapCompletion<Self, F>
+    where
+        Self: Sized,
+        F: for<'c> Fn(Completion<'c>) -> Completion<'c>,
+    {
+        MapCompletion {
+            completer: self,
+            map,
+        }
+    }
+
+    /// Drops the completions of this completer for which `predicate` returns `false`.
+    #[must_use]
+    fn filter_completion<F>(self, predicate: F) -> FilterCompletion<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Completion<'_>) -> bool,
+    {
+        FilterCompletion {
+            completer: self,
+            predicate,
+        }
+    }
+}
+
+/// Generates a list of possible values for the [`prompt`] buffer, usually associated with the
+/// `Tab` key.
+///
+/// Whenever the [`Suggest`] action is triggered,  the [`prompt`] will ask the
+/// `Suggester` for a list of values to **replace** to the current buffer, or a [`span`] of it.
+/// This list is kept by the [`prompt`] for cycling back and forth until it is dropped by
+/// either accepting a suggestion or canceling it. The implementation
+/// may use the [`Buffer`] to decide which completions are applicable.
+///
+/// The buffer is not actually changed until the suggestion is accepted by either a [`Write`], a
+/// [`Delete`], [`Accept`] or a [`Move`], while a suggestion is selected.
+///
+/// # See also
+/// * [`Completer`]
+///
+/// [`Accept`]: ../actions/enum.Action.html#variant.Accept
+/// [`Buffer`]: ../buffer/struct.Buffer.html
+/// [`Completer`]: trait.Completer.html
+/// [`Delete`]: ../actions/enum.Action.html#variant.Delete
+/// [`Move`]: ../actions/enum.Action.html#variant.Move
+/// [`Suggest`]: ../actions/enum.Action.html#variant.Suggest
+/// [`Write`]: ../actions/enum.Action.html#variant.Write
+/// [`prompt`]: ../prompt/index.html
+/// [`span`]: struct.Suggestion.html#method.with_span
+pub trait Suggester {
+    /// Whenever the [`Suggest`] action is triggered, the [`prompt`] will call `suggest_for`
+    /// for a list of values to **replace** to the current buffer.
+    ///
+    /// # Examples
+    ///
+    /// Basic implementation:
+    ///
+    /// ```no_run
+    /// # use rucline::Buffer;
+    /// # use rucline::completion::Suggestion;
+    /// # struct Basic(Vec<String>);
+    /// # impl rucline::completion::Suggester for Basic {
+    ///  fn suggest_for(&self, _: &Buffer) -> Vec<Suggestion<'_>> {
+    ///     self.0.iter().map(Into::into).collect()
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    /// * [`buffer`] - Read-only view into the line buffer, providing the context in which this
+    ///   event is happening.
+    ///
+    /// # Return
+    /// * The list of [`suggestions`] to be rendered as drop-down options. Empty if none.
+    ///
+    /// [`Buffer`]: ../buffer/struct.Buffer.html
+    /// [`Suggest`]: ../actions/enum.Action.html#variant.Suggest
+    /// [`prompt`]: ../prompt/index.html
+    /// [`suggestions`]: struct.Suggestion.html
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>>;
+
+    /// Returns `true` while suggestions are being computed elsewhere, e.g. in the [`Background`].
+    ///
+    /// If no suggestions were returned while pending, the [`prompt`] keeps polling this method and
+    /// calls [`suggest_for`] again once it returns `false`.
+    ///
+    /// [`Background`]: struct.Background.html
+    /// [`prompt`]: ../prompt/index.html
+    /// [`suggest_for`]: trait.Suggester.html#tymethod.suggest_for
+    fn is_suggestion_pending(&self) -> bool {
+        false
+    }
+
+    /// Combines this suggester with `other`, listing the suggestions of `other` after the ones of
+    /// this suggester.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rucline::completion::Suggester;
+    ///
+    /// let suggester = vec!["commit", "checkout"]
+    ///     .chain(vec!["checkout", "cherry-pick"])
+    ///     .unique()
+    ///     .limit(3);
+    /// assert_eq!(
+    ///     suggester.suggest_for(&"".into()),
+    ///     vec!["commit", "checkout", "cherry-pick"]
+    /// );
+    /// ```
+    #[must_use]
+    fn chain<S: Suggester>(self, other: S) -> Chain<Self, S>
+    where
+        Self: Sized,
+    {
+        Chain {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Transforms each suggestion of this suggester with `map`, e.g. to add a description.
+    #[must_use]
+    fn map_suggestion<F>(self, map: F) -> MapSuggestion<Self, F>
+    where
+        Self: Sized,
+        F: for<'s> Fn(Suggestion<'s>) -> Suggestion<'s>,
+    {
+        MapSuggestion {
+            suggester: self,
+            map,
+        }
+    }
+
+    /// Drops the suggestions of this suggester for which `predicate` returns `false`.
+    #[must_use]
+    fn filter_suggestion<F>(self, predicate: F) -> FilterSuggestion<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Suggestion<'_>) -> bool,
+    {
+        FilterSuggestion {
+            suggester: self,
+            predicate,
+        }
+    }
+
+    /// Drops the suggestions of this suggester with the same [`replacement`] as an earlier one.
+    ///
+    /// [`replacement`]: struct.Suggestion.html#method.replacement
+    #[must_use]
+    fn unique(self) -> Unique<Self>
+    where
+        Self: Sized,
+    {
+        Unique { suggester: self }
+    }
+
+    /// Keeps at most the first `limit` suggestions of this suggester.
+    #[must_use]
+    fn limit(self, limit: usize) -> Limit<Self>
+    where
+        Self: Sized,
+    {
+        Limit {
+            suggester: self,
+            limit,
+        }
+    }
+}
+
+macro_rules! impl_completion {
+    (completer) => {
+        fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+            if buffer.is_empty() {
+                None
+            } else {
+                self.iter().find_map(|completion| {
+                    if completion.as_ref().starts_with(buffer.as_str()) {
+                        Some((completion.as_ref()[buffer.len()..]).into())
+                    } else {
+                        None
+                    }
+                })
+            }
+        }
+    };
+
+    (suggester) => {
+        fn suggest_for(&self, _: &Buffer) -> Vec<Suggestion<'_>> {
+            self.iter()
+                .map(|suggestion| suggestion.as_ref().into())
+                .collect()
+        }
+    };
+}
+
+impl<S: AsRef<str>> Completer for Vec<S> {
+    impl_completion!(completer);
+}
+
+impl<S: AsRef<str>, const N: usize> Completer for [S; N] {
+    impl_completion!(completer);
+}
+
+impl<S: AsRef<str>> Completer for &[S] {
+    impl_completion!(completer);
+}
+
+impl<S: AsRef<str>> Suggester for Vec<S> {
+    impl_completion!(suggester);
+}
+
+impl<S: AsRef<str>, const N: usize> Suggester for [S; N] {
+    impl_completion!(suggester);
+}
+
+impl<S: AsRef<str>> Suggester for &[S] {
+    impl_completion!(suggester);
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Buffer, Completer, Completion, Suggester};
+
+    #[test]
+    fn should_not_complete_if_empty() {
+        let list = ["some programmer was here", "some developer was there"];
+        assert_eq!(list.complete_for(&Buffer::new()), None);
+    }
+
+    #[test]
+    fn should_not_complete_if_context_is_different() {
+        let list = ["some programmer was here", "some developer was there"];
+        assert_eq!(list.complete_for(&"a".into()), None);
+    }
+
+    #[test]
+    fn complete_the_first_match() {
+        let list = ["zz", "b3", "b2"];
+        let expected = Completion::from("3");
+        assert_eq!(list.complete_for(&"b".into()), Some(expected));
+    }
+
+    #[test]
+    fn only_complete_the_remainder() {
+        let list = ["abcd", "abc"];
+        let expected = Completion::from("d");
+        assert_eq!(list.complete_for(&"abc".into()), Some(expected));
+    }
+
+    #[test]
+    fn always_suggest() {
+        let list = ["a", "b", "c"];
+        let expected = vec!["a", "b", "c"];
+        assert_eq!(&list.suggest_for(&Buffer::new()), &expected);
+        assert_eq!(&list.suggest_for(&"a".into()), &expected);
+        assert_eq!(&list.suggest_for(&"z".into()), &expected);
+    }
+}
@@ -0,0 +1,258 @@
+use super::{Buffer, Completer, Completion, Suggester, Suggestion};
+
+/// Completes and suggests filesystem paths for the token under the cursor.
+///
+/// The directory entries that start with the name being typed are offered, with a trailing `/`
+/// for directories. A leading `~` is expanded to the home directory when listing, but kept in the
+/// buffer. Names with spaces or other special characters are escaped with a backslash, unless the
+/// token was opened with a quote, in which case the quote is kept and closed after file names.
+///
+/// Only the token under the cursor is replaced, leaving the rest of the line in place.
+///
+/// # Example
+///
+/// ```no_run
+/// use rucline::completion::Paths;
+/// use rucline::prompt::{Builder, Prompt};
+///
+/// let prompt = Prompt::from("$ ")
+///     .completer(Paths::new())
+///     .suggester(Paths::new().hide_dotfiles(true));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct Paths {
+    hide_dotfiles: bool,
+}
+
+impl Paths {
+    /// Creates a new [`Paths`] that lists all entries, including dotfiles.
+    ///
+    /// [`Paths`]: struct.Paths.html
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether entries starting with a `.` are hidden.
+    ///
+    /// Even when hidden, dotfiles are listed if the name being typed starts with a `.`.
+    ///
+    /// # Arguments
+    /// * `hide_dotfiles` - If dotfiles should be hidden.
+    #[must_use]
+    pub fn hide_dotfiles(mut self, hide_dotfiles: bool) -> Self {
+        self.hide_dotfiles = hide_dotfiles;
+        self
+    }
+
+    // The span of the current token, whether a name is being typed in it, and the matching entries
+    fn entries(self, buffer: &Buffer) -> Option<(std::ops::Range<usize>, bool, Vec<Entry>)> {
+        // Only the part of the token before the cursor is completed
+        let line = Buffer::from(&buffer[..buffer.cursor()]);
+        let token = line.current_token();
+        let span = token.span();
+        let quote = line[span.clone()]
+            .chars()
+            .next()
+            .filter(|c| *c == '\'' || *c == '"');
+
+        let (directory, prefix) = if token.text() == "~" {
+            ("~/", "")
+        } else {
+            token
+                .text()
+                .rfind('/')
+                .map_or(("", token.text()), |index| token.text().split_at(index + 1))
+        };
+
+        let path = if directory.is_empty() {
+            std::path::PathBuf::from(".")
+        } else if let Some(rest) = directory.strip_prefix("~/") {
+            home()?.join(rest)
+        } else {
+            std::path::PathBuf::from(directory)
+        };
+
+        let mut entries = std::fs::read_dir(path)
+            .ok()?
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                if !name.starts_with(prefix)
+                    || (self.hide_dotfiles && name.starts_with('.') && !prefix.starts_with('.'))
+                {
+                    return None;
+                }
+
+                let is_directory = entry.path().is_dir();
+                Some(Entry {
+                    replacement: escape(&format!("{directory}{name}"), quote, is_directory),
+                    display: if is_directory { name + "/" } else { name },
+                })
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.display.cmp(&b.display));
+
+        Some((span, !prefix.is_empty(), entries))
+    }
+}
+
+impl Completer for Paths {
+    fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+        let (span, typing, entries) = self.entries(buffer)?;
+        if typing {
+            entries
+                .into_iter()
+                .next()
+                .map(|entry| Completion::replacing(span, entry.replacement))
+        } else {
+            None
+        }
+    }
+}
+
+impl Suggester for Paths {
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
+        self.entries(buffer)
+            .map(|(span, _, entries)| {
+                entries
+                    .into_iter()
+                    .map(|entry| {
+                        Suggestion::new(entry.replacement)
+                            .with_display(entry.display)
+                            .with_span(span.clone())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+struct Entry {
+    replacement: String,
+    display: String,
+}
+
+fn escape(path: &str, quote: Option<char>, is_directory: bool) -> String {
+    let suffix = if is_directory { "/" } else { "" };
+
+    // Directories are left open, so that the path can be continued
+    if let Some(quote) = quote {
+        let close = if is_directory {
+            String::new()
+        } else {
+            quote.to_string()
+        };
+        let path = if quote == '"' {
+            path.replace('\\', "\\\\").replace('"', "\\\"")
+        } else {
+            path.to_owned()
+        };
+        format!("{quote}{path}{suffix}{close}")
+    } else {
+        let mut escaped = String::with_capacity(path.len() + suffix.len());
+        for (index, c) in path.char_indices() {
+            if c.is_whitespace() || "\\'\"`$&|;<>()[]{}*?!#".contains(c) || (c == '~' && index > 0)
+            {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped.push_str(suffix);
+        escaped
+    }
+}
+
+fn home() -> Option<std::path::PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(std::path::PathBuf::from)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{escape, Buffer, Completer, Paths, Suggester};
+
+    struct Directory(std::path::PathBuf);
+
+    impl Directory {
+        fn new(name: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("rucline-paths-{}-{name}", std::process::id()));
+            std::fs::create_dir_all(path.join("alpha dir")).unwrap();
+            for file in ["alpha.txt", "beta", ".hidden"] {
+                std::fs::write(path.join(file), "").unwrap();
+            }
+            Self(path)
+        }
+
+        fn path(&self) -> String {
+            self.0.to_str().unwrap().to_owned()
+        }
+    }
+
+    impl Drop for Directory {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn suggest_entries_for_the_current_token() {
+        let directory = Directory::new("suggest");
+        let buffer = Buffer::from(format!("cat {}/al", directory.path()));
+
+        let paths = Paths::new();
+        let suggestions = paths.suggest_for(&buffer);
+        let displays = suggestions
+            .iter()
+            .map(super::Suggestion::display)
+            .collect::<Vec<_>>();
+        assert_eq!(displays, vec!["alpha dir/", "alpha.txt"]);
+        assert_eq!(
+            suggestions[0].replacement(),
+            format!("{}/alpha\\ dir/", directory.path())
+        );
+        assert_eq!(suggestions[0].span(), Some(4..buffer.len()));
+    }
+
+    #[test]
+    fn hide_dotfiles_unless_typed() {
+        let directory = Directory::new("dotfiles");
+        let all = Buffer::from(format!("{}/", directory.path()));
+        let dotfiles = Buffer::from(format!("{}/.", directory.path()));
+
+        assert_eq!(Paths::new().suggest_for(&all).len(), 4);
+        assert_eq!(Paths::new().hide_dotfiles(true).suggest_for(&all).len(), 3);
+        assert_eq!(
+            Paths::new()
+                .hide_dotfiles(true)
+                .suggest_for(&dotfiles)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn complete_in_place_within_quotes() {
+        let directory = Directory::new("quotes");
+        let buffer = Buffer::from(format!("cat \"{}/be", directory.path()));
+
+        let paths = Paths::new();
+        let completion = paths.complete_for(&buffer).unwrap();
+        assert_eq!(
+            completion,
+            format!("\"{}/beta\"", directory.path()).as_str()
+        );
+        assert_eq!(completion.span(), Some(4..buffer.len()));
+        assert!(paths.complete_for(&"cat ".into()).is_none());
+    }
+
+    #[test]
+    fn escape_special_characters() {
+        assert_eq!(escape("my file (1)", None, false), r"my\ file\ \(1\)");
+        assert_eq!(escape("~/dir", None, true), "~/dir/");
+        assert_eq!(escape("my file", Some('\''), false), "'my file'");
+        assert_eq!(escape("my dir", Some('"'), true), "\"my dir/");
+    }
+}
@@ -0,0 +1,205 @@
+use std::borrow::Cow;
+
+/// A single option provided by a [`Suggester`].
+///
+/// At its simplest, a `Suggestion` is the text that will **replace** the buffer when it is
+/// accepted. It may, however, replace only a span of the buffer, e.g. the word under the cursor.
+/// It may also carry a different text to be displayed in the list of suggestion options, a
+/// description to be rendered in an aligned column next to it, and a style for rendering it.
+///
+/// Strings convert into a `Suggestion` that is displayed as is, with no description or style.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::style::{ContentStyle, Stylize};
+/// use rucline::completion::Suggestion;
+///
+/// let plain = Suggestion::from("commit");
+/// assert_eq!(plain.replacement(), "commit");
+/// assert_eq!(plain.display(), "commit");
+///
+/// let rich = Suggestion::new("commit --amend")
+///     .with_display("amend")
+///     .with_description("Rewrite the last commit")
+///     .with_style(ContentStyle::new().yellow());
+/// assert_eq!(rich.replacement(), "commit --amend");
+/// assert_eq!(rich.display(), "amend");
+/// assert_eq!(rich.description(), Some("Rewrite the last commit"));
+/// ```
+///
+/// [`Suggester`]: trait.Suggester.html
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Suggestion<'a> {
+    replacement: Cow<'a, str>,
+    display: Option<Cow<'a, str>>,
+    description: Option<Cow<'a, str>>,
+    style: Option<crossterm::style::ContentStyle>,
+    highlights: Vec<usize>,
+    span: Option<std::ops::Range<usize>>,
+}
+
+impl<'a> Suggestion<'a> {
+    /// Creates a new [`Suggestion`] that will replace the buffer with `replacement`.
+    ///
+    /// [`Suggestion`]: struct.Suggestion.html
+    #[must_use]
+    pub fn new<S: Into<Cow<'a, str>>>(replacement: S) -> Self {
+        Self {
+            replacement: replacement.into(),
+            display: None,
+            description: None,
+            style: None,
+            highlights: Vec::new(),
+            span: None,
+        }
+    }
+
+    /// Sets the text to be rendered in the list of suggestion options, instead of the
+    /// replacement text.
+    #[must_use]
+    pub fn with_display<S: Into<Cow<'a, str>>>(mut self, display: S) -> Self {
+        self.display = Some(display.into());
+        self
+    }
+
+    /// Sets the description to be rendered next to this suggestion in the list of suggestion
+    /// options.
+    #[must_use]
+    pub fn with_description<S: Into<Cow<'a, str>>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the style in which this suggestion is rendered in the list of suggestion options.
+    #[must_use]
+    pub fn with_style(mut self, style: crossterm::style::ContentStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Sets which characters of the display text are highlighted in the list of suggestion
+    /// options, e.g. the characters that matched what the user typed.
+    ///
+    /// # Arguments
+    /// * `highlights` - The byte indices of the highlighted characters of the display text.
+    ///   Indices that do not fall on a character of the display text are ignored.
+    #[must_use]
+    pub fn with_highlights(mut self, highlights: Vec<usize>) -> Self {
+        self.highlights = highlights;
+        self
+    }
+
+    /// Sets the span of the buffer that is replaced by this suggestion, instead of the whole
+    /// buffer.
+    ///
+    /// # Arguments
+    /// * `span` - The byte range of the buffer to be replaced.
+    #[must_use]
+    pub fn with_span(mut self, span: std::ops::Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Returns the text that will replace the buffer if this suggestion is accepted.
+    #[inline]
+    #[must_use]
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    /// Returns the text rendered in the list of suggestion options.
+    ///
+    /// This is the replacement text, unless a separate display text was given.
+    #[inline]
+    #[must_use]
+    pub fn display(&self) -> &str {
+        self.display.as_deref().unwrap_or(&self.replacement)
+    }
+
+    /// Returns the description rendered next to this suggestion, if any.
+    #[inline]
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the style in which this suggestion is rendered, if any.
+    #[inline]
+    #[must_use]
+    pub fn style(&self) -> Option<crossterm::style::ContentStyle> {
+        self.style
+    }
+
+    /// Returns the byte indices of the highlighted characters of the display text.
+    #[inline]
+    #[must_use]
+    pub fn highlights(&self) -> &[usize] {
+        &self.highlights
+    }
+
+    /// Returns the byte range of the buffer replaced by this suggestion, or `None` if the whole
+    /// buffer is replaced.
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> Option<std::ops::Range<usize>> {
+        self.span.clone()
+    }
+
+    /// Converts this suggestion into one that owns all of its text.
+    #[must_use]
+    pub fn into_owned(self) -> Suggestion<'static> {
+        Suggestion {
+            replacement: Cow::Owned(self.replacement.into_owned()),
+            display: self.display.map(|display| Cow::Owned(display.into_owned())),
+            description: self
+                .description
+                .map(|description| Cow::Owned(description.into_owned())),
+            style: self.style,
+            highlights: self.highlights,
+            span: self.span,
+        }
+    }
+
+    /// Consumes this suggestion, returning the replacement text.
+    #[must_use]
+    pub fn into_replacement(self) -> Cow<'a, str> {
+        self.replacement
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for Suggestion<'a> {
+    fn from(replacement: Cow<'a, str>) -> Self {
+        Self::new(replacement)
+    }
+}
+
+impl<'a> From<&'a str> for Suggestion<'a> {
+    fn from(replacement: &'a str) -> Self {
+        Self::new(replacement)
+    }
+}
+
+impl<'a> From<&'a String> for Suggestion<'a> {
+    fn from(replacement: &'a String) -> Self {
+        Self::new(replacement.as_str())
+    }
+}
+
+impl From<String> for Suggestion<'_> {
+    fn from(replacement: String) -> Self {
+        Self::new(replacement)
+    }
+}
+
+impl PartialEq<str> for Suggestion<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.replacement == other
+    }
+}
+
+impl PartialEq<&str> for Suggestion<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.replacement == *other
+    }
+}
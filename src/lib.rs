@@ -41,6 +41,9 @@
 //! * `CTRL` + `U`: delete the whole line
 //! * `CTRL` + `H`: delete until the beggining of the line
 //! * `CTRL` + `L`: delete until the end of the line
+//! * `CTRL` + `D`: delete the next character, or end the input if the line is empty
+//! * `CTRL` + `C`: interrupt the input
+//! * `CTRL` + `X`: edit the line in `$VISUAL` or `$EDITOR`
 //!
 //! > See [`Action`] for the full default behavior specification
 //!
@@ -83,8 +86,9 @@ pub mod actions;
 mod buffer;
 pub mod completion;
 pub mod prompt;
+pub mod repl;
 
-pub use buffer::Buffer;
+pub use buffer::{Buffer, Token};
 pub use prompt::Outcome;
 pub use prompt::Prompt;
 
@@ -1,7 +1,7 @@
-use super::Outcome;
+use super::{Constraints, Layout, Options, Outcome, TabBehavior};
 
-use crate::actions::{Action, Event, Overrider};
-use crate::completion::{Completer, Suggester};
+use crate::actions::{Action, Editor, EditorOverrider, Event, Handler, Overrider};
+use crate::completion::{Completer, Completion, Suggester, Suggestion};
 use crate::Buffer;
 use crate::Error;
 
@@ -23,10 +23,53 @@ macro_rules! impl_builder {
                 .display_suggestion_options(display_suggestion_options);
             self
         }
+
+        fn suggestion_options_layout(mut self, suggestion_options_layout: Layout) -> Self {
+            self.base = self
+                .base
+                .suggestion_options_layout(suggestion_options_layout);
+            self
+        }
+
+        fn suggestion_options_height(mut self, suggestion_options_height: usize) -> Self {
+            self.base = self
+                .base
+                .suggestion_options_height(suggestion_options_height);
+            self
+        }
+
+        fn narrow_suggestions(mut self, narrow_suggestions: bool) -> Self {
+            self.base = self.base.narrow_suggestions(narrow_suggestions);
+            self
+        }
+
+        fn tab_behavior(mut self, tab_behavior: TabBehavior) -> Self {
+            self.base = self.base.tab_behavior(tab_behavior);
+            self
+        }
+
+        fn constraints(mut self, constraints: Constraints) -> Self {
+            self.base = self.base.constraints(constraints);
+            self
+        }
+
+        fn placeholder<S: Into<String>>(mut self, placeholder: S) -> Self {
+            self.base = self.base.placeholder(placeholder);
+            self
+        }
+
+        fn history<I, S>(mut self, history: I) -> Self
+        where
+            I: IntoIterator<Item = S>,
+            S: Into<String>,
+        {
+            self.base = self.base.history(history);
+            self
+        }
     };
 
     (extensions) => {
-        fn overrider<O: Overrider>(self, overrider: O) -> WithOverrider<O, Self> {
+        fn overrider<O: EditorOverrider>(self, overrider: O) -> WithOverrider<O, Self> {
             WithOverrider {
                 base: self,
                 overrider,
@@ -43,7 +86,7 @@ macro_rules! impl_builder {
             }
         }
 
-        fn overrider_ref<O: Overrider>(self, overrider: &O) -> WithRefOverrider<'_, O, Self> {
+        fn overrider_ref<O: EditorOverrider>(self, overrider: &O) -> WithRefOverrider<'_, O, Self> {
             WithRefOverrider {
                 base: self,
                 overrider,
@@ -63,7 +106,7 @@ macro_rules! impl_builder {
         ) -> WithCompleter<Closure<'a, F, Option<R>>, Self>
         where
             F: Fn(&Buffer) -> Option<R>,
-            R: Into<std::borrow::Cow<'a, str>>,
+            R: Into<$crate::completion::Completion<'a>>,
         {
             WithCompleter {
                 base: self,
@@ -91,7 +134,7 @@ macro_rules! impl_builder {
         fn suggester_fn<'a, F, R>(self, closure: F) -> WithSuggester<Closure<'a, F, Vec<R>>, Self>
         where
             F: Fn(&Buffer) -> Vec<R>,
-            R: Into<std::borrow::Cow<'a, str>>,
+            R: Into<$crate::completion::Suggestion<'a>>,
         {
             WithSuggester {
                 base: self,
@@ -108,6 +151,30 @@ macro_rules! impl_builder {
                 suggester,
             }
         }
+
+        fn handler<H: Handler>(self, handler: H) -> WithHandler<H, Self> {
+            WithHandler {
+                base: self,
+                handler,
+            }
+        }
+
+        fn handler_fn<H>(self, handler: H) -> WithHandler<H, Self>
+        where
+            H: Fn(u32, &mut dyn $crate::actions::Editor) -> $crate::actions::Action,
+        {
+            WithHandler {
+                base: self,
+                handler,
+            }
+        }
+
+        fn handler_ref<H: Handler>(self, handler: &H) -> WithRefHandler<'_, H, Self> {
+            WithRefHandler {
+                base: self,
+                handler,
+            }
+        }
     };
 }
 
@@ -121,12 +188,14 @@ macro_rules! impl_builder {
 /// ```no_run
 /// use rucline::prompt::{Builder, Prompt};
 ///
-/// let outcome = Prompt::from("Delete file? ")
-///     .completer(vec!["yes", "no"])
+/// let outcome = Prompt::from("Branch: ")
+///     .completer(vec!["main", "develop"])
 ///     .erase_after_read(true)
 ///     .read_line();
 /// ```
 ///
+/// For yes/no questions, see [`Confirm`] instead.
+///
 /// # Re-using the prompt configuration
 ///
 /// The builder is consumed on every method call, including the [`read_line`] method. To re-use a
@@ -166,6 +235,22 @@ macro_rules! impl_builder {
 ///     .completer(some_other_completions);  // Superseeds the previous completer
 /// ```
 ///
+/// To use more than one source, combine them with [`Completer::or`] or [`Suggester::chain`]
+/// instead:
+/// ```no_run
+/// use rucline::completion::Completer;
+/// use rucline::prompt::{Builder, Prompt};
+///
+/// let some_completions = vec!["yes", "no"];
+/// let some_other_completions = vec!["ja", "nei"];
+///
+/// let prompt = Prompt::from("Delete file? ")
+///     .completer(some_completions.or(some_other_completions));
+/// ```
+///
+/// [`Completer::or`]: ../completion/trait.Completer.html#method.or
+/// [`Confirm`]: struct.Confirm.html
+/// [`Suggester::chain`]: ../completion/trait.Suggester.html#method.chain
 /// [`prompt::read_line`]: fn.read_line.html
 /// [`read_line`]: trait.Builder.html#tymethod.read_line.html
 pub trait Builder: ChainedLineReader + Sized {
@@ -204,7 +289,104 @@ pub trait Builder: ChainedLineReader + Sized {
     #[must_use]
     fn display_suggestion_options(self, display_suggestion_options: bool) -> Self;
 
-    /// Modifies the behavior of the prompt by setting an [`Overrider`].
+    /// Controls how the list of suggestion options is laid out.
+    ///
+    /// If set to [`List`] (default), each suggestion option is rendered in its own row.
+    ///
+    /// If set to [`Grid`], the suggestion options are rendered in as many columns as fit the
+    /// terminal width. While the options are displayed, the `Left` and `Right` keys move the
+    /// selection between columns.
+    ///
+    /// # Arguments
+    /// * `suggestion_options_layout` - The layout of the list of suggestion options.
+    ///
+    /// [`List`]: enum.Layout.html#variant.List
+    /// [`Grid`]: enum.Layout.html#variant.Grid
+    #[must_use]
+    fn suggestion_options_layout(self, suggestion_options_layout: Layout) -> Self;
+
+    /// Limits how many rows the list of suggestion options may take.
+    ///
+    /// By default, every suggestion is listed in its own row. If a height is set and there are
+    /// more suggestions than rows, the list will scroll to follow the selected suggestion and a
+    /// `n/m` position indicator will be rendered underneath.
+    ///
+    /// # Arguments
+    /// * `suggestion_options_height` - The maximum number of visible suggestion options.
+    #[must_use]
+    fn suggestion_options_height(self, suggestion_options_height: usize) -> Self;
+
+    /// Controls if typing narrows down the list of suggestion options.
+    ///
+    /// If set to `false` (default), typing while a suggestion is selected accepts the suggestion
+    /// into the buffer and closes the list of suggestion options.
+    ///
+    /// If set to `true`, typing while the list of suggestion options is displayed edits the
+    /// buffer and filters the list, keeping only the options that fuzzy match the buffer. The
+    /// list stays open, making it an interactive picker: [`Accept`] takes the selected option and
+    /// [`Cancel`] closes the list, keeping what was typed.
+    ///
+    /// # Arguments
+    /// * `narrow_suggestions` - Whether typing narrows down the list of suggestion options.
+    ///
+    /// [`Accept`]: ../actions/enum.Action.html#variant.Accept
+    /// [`Cancel`]: ../actions/enum.Action.html#variant.Cancel
+    #[must_use]
+    fn narrow_suggestions(self, narrow_suggestions: bool) -> Self;
+
+    /// Controls what the first [`Suggest`] action does, usually associated with the `Tab` key.
+    ///
+    /// If set to [`Cycle`] (default), the first suggestion is selected right away.
+    ///
+    /// If set to [`CommonPrefix`], the longest common prefix of all suggestions is inserted
+    /// first, and only the following [`Suggest`] actions list and cycle through the suggestions.
+    ///
+    /// # Arguments
+    /// * `tab_behavior` - What the first [`Suggest`] action does.
+    ///
+    /// [`Cycle`]: enum.TabBehavior.html#variant.Cycle
+    /// [`CommonPrefix`]: enum.TabBehavior.html#variant.CommonPrefix
+    /// [`Suggest`]: ../actions/enum.Action.html#variant.Suggest
+    #[must_use]
+    fn tab_behavior(self, tab_behavior: TabBehavior) -> Self;
+
+    /// Restricts what can be typed, completed or pasted into the buffer.
+    ///
+    /// Defaults to no restrictions.
+    ///
+    /// # Arguments
+    /// * [`constraints`] - The restrictions on the buffer.
+    ///
+    /// [`constraints`]: struct.Constraints.html
+    #[must_use]
+    fn constraints(self, constraints: Constraints) -> Self;
+
+    /// Shows a dimmed hint while the buffer is empty, e.g. `type a hostname…`.
+    ///
+    /// The hint is not part of the buffer: it disappears as soon as anything is typed, and it is
+    /// never accepted.
+    ///
+    /// # Arguments
+    /// * `placeholder` - The hint to show.
+    #[must_use]
+    fn placeholder<S: Into<String>>(self, placeholder: S) -> Self;
+
+    /// Sets the lines that the [`Recall`] action steps through, most recent first.
+    ///
+    /// By default, `Up` recalls the previous line and `Down` the next one, until the line that
+    /// was being typed is restored.
+    ///
+    /// # Arguments
+    /// * `history` - The lines previously read, most recent first.
+    ///
+    /// [`Recall`]: ../actions/enum.Action.html#variant.Recall
+    #[must_use]
+    fn history<I, S>(self, history: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>;
+
+    /// Modifies the behavior of the prompt by setting an [`Overrider`] or an [`EditorOverrider`].
     ///
     /// The builder will take ownership of [`overrider`]. To pass in a reference, use
     /// [`overrider_ref`].
@@ -212,9 +394,11 @@ pub trait Builder: ChainedLineReader + Sized {
     /// # Arguments
     /// * [`overrider`] - The new overrider.
     ///
-    /// [`overrider`]: ../actions/trait.Overrider.html
+    /// [`EditorOverrider`]: ../actions/trait.EditorOverrider.html
+    /// [`Overrider`]: ../actions/trait.Overrider.html
+    /// [`overrider`]: ../actions/trait.EditorOverrider.html
     /// [`overrider_ref`]: trait.Builder.html#tymethod.overrider_ref
-    fn overrider<O: Overrider>(self, overrider: O) -> WithOverrider<O, Self>;
+    fn overrider<O: EditorOverrider>(self, overrider: O) -> WithOverrider<O, Self>;
 
     /// Modifies the behavior of the prompt by setting an [`Overrider`] closure.
     ///
@@ -226,13 +410,15 @@ pub trait Builder: ChainedLineReader + Sized {
     where
         O: Fn(Event, &Buffer) -> Option<Action>;
 
-    /// Modifies the behavior of the prompt by setting a [`Overrider`] reference.
+    /// Modifies the behavior of the prompt by setting an [`Overrider`] or an [`EditorOverrider`]
+    /// reference.
     ///
     /// # Arguments
     /// * [`overrider`] - The new overrider reference.
     ///
+    /// [`EditorOverrider`]: ../actions/trait.EditorOverrider.html
     /// [`Overrider`]: ../actions/trait.Overrider.html
-    fn overrider_ref<O: Overrider>(self, overrider: &O) -> WithRefOverrider<'_, O, Self>;
+    fn overrider_ref<O: EditorOverrider>(self, overrider: &O) -> WithRefOverrider<'_, O, Self>;
 
     /// Sets the in-line completion provider.
     ///
@@ -255,7 +441,7 @@ pub trait Builder: ChainedLineReader + Sized {
     fn completer_fn<'a, F, R>(self, closure: F) -> WithCompleter<Closure<'a, F, Option<R>>, Self>
     where
         F: Fn(&Buffer) -> Option<R>,
-        R: Into<std::borrow::Cow<'a, str>>;
+        R: Into<Completion<'a>>;
 
     /// Sets the in-line completion provider reference.
     ///
@@ -286,7 +472,7 @@ pub trait Builder: ChainedLineReader + Sized {
     fn suggester_fn<'a, F, R>(self, closure: F) -> WithSuggester<Closure<'a, F, Vec<R>>, Self>
     where
         F: Fn(&Buffer) -> Vec<R>,
-        R: Into<std::borrow::Cow<'a, str>>;
+        R: Into<Suggestion<'a>>;
 
     /// Sets the drop-down suggestion provider reference.
     ///
@@ -297,6 +483,40 @@ pub trait Builder: ChainedLineReader + Sized {
     /// [`Suggester`]: ../completion/trait.Suggester.html
     fn suggester_ref<S: Suggester>(self, suggester: &S) -> WithRefSuggester<'_, S, Self>;
 
+    /// Sets the [`Handler`] of [`Custom`] actions.
+    ///
+    /// The builder will take ownership of [`handler`]. To pass in a reference, use
+    /// [`handler_ref`].
+    ///
+    /// # Arguments
+    /// * [`handler`] - The new handler.
+    ///
+    /// [`Custom`]: ../actions/enum.Action.html#variant.Custom
+    /// [`Handler`]: ../actions/trait.Handler.html
+    /// [`handler`]: ../actions/trait.Handler.html
+    /// [`handler_ref`]: trait.Builder.html#tymethod.handler_ref
+    fn handler<H: Handler>(self, handler: H) -> WithHandler<H, Self>;
+
+    /// Sets the [`Handler`] closure of [`Custom`] actions.
+    ///
+    /// # Arguments
+    /// * [`handler`] - The new handler.
+    ///
+    /// [`Custom`]: ../actions/enum.Action.html#variant.Custom
+    /// [`Handler`]: ../actions/trait.Handler.html
+    fn handler_fn<H>(self, handler: H) -> WithHandler<H, Self>
+    where
+        H: Fn(u32, &mut dyn Editor) -> Action;
+
+    /// Sets the [`Handler`] reference of [`Custom`] actions.
+    ///
+    /// # Arguments
+    /// * [`handler`] - The new handler reference.
+    ///
+    /// [`Custom`]: ../actions/enum.Action.html#variant.Custom
+    /// [`Handler`]: ../actions/trait.Handler.html
+    fn handler_ref<H: Handler>(self, handler: &H) -> WithRefHandler<'_, H, Self>;
+
     /// Consumes this [`Builder`] to craft an invocation of [`prompt::read_line`].
     ///
     /// # Errors
@@ -306,19 +526,53 @@ pub trait Builder: ChainedLineReader + Sized {
     /// [`prompt::read_line`]: fn.read_line.html
     /// [`Error`]: ../enum.Error.html
     fn read_line(self) -> Result<Outcome, Error>;
+
+    /// Consumes this [`Builder`] to read a line and parse it into `T`.
+    ///
+    /// Accepting text that does not parse keeps the prompt open with the text as typed, so that
+    /// it can be fixed, and renders the parse error underneath it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rucline::Outcome::Accepted;
+    /// use rucline::prompt::{Builder, Prompt};
+    ///
+    /// if let Ok(Accepted(port)) = Prompt::from("Port: ").read_parsed::<u16>() {
+    ///     println!("Listening on {port}");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// * [`Error`] - If an error occurred while reading the user input.
+    ///
+    /// [`Builder`]: trait.Builder.html
+    /// [`Error`]: ../enum.Error.html
+    fn read_parsed<T>(self) -> Result<Outcome<T>, Error>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        self.chain_read_line::<T, Dummy, Dummy, Dummy, Dummy>(None, None, None, None, &|text| {
+            text.parse().map_err(|error: T::Err| error.to_string())
+        })
+    }
 }
 
 pub trait ChainedLineReader {
-    fn chain_read_line<O, C, S>(
+    fn chain_read_line<P, O, C, S, H>(
         self,
         overrider: Option<&O>,
         completer: Option<&C>,
         suggester: Option<&S>,
-    ) -> Result<Outcome, Error>
+        handler: Option<&H>,
+        parse: &dyn Fn(&str) -> Result<P, String>,
+    ) -> Result<Outcome<P>, Error>
     where
-        O: Overrider + ?Sized,
+        O: EditorOverrider + ?Sized,
         C: Completer + ?Sized,
-        S: Suggester + ?Sized;
+        S: Suggester + ?Sized,
+        H: Handler + ?Sized;
 }
 
 /// The base struct for building a line reader prompt.
@@ -335,12 +589,13 @@ pub trait ChainedLineReader {
 /// ```
 ///
 /// [`Builder`]: trait.Builder.html
+// Allowed because the prompt text is what `Prompt` wraps
+#[allow(clippy::struct_field_names)]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Prompt {
     prompt: Option<String>,
     buffer: Option<Buffer>,
-    erase_after_read: bool,
-    display_suggestion_options: bool,
+    options: Options,
 }
 
 impl Prompt {
@@ -352,10 +607,35 @@ impl Prompt {
         Self {
             prompt: None,
             buffer: None,
-            erase_after_read: false,
-            display_suggestion_options: true,
+            options: Options::new(),
         }
     }
+
+    // Reads a line with these settings and hooks that may be unsized, e.g. when they are only
+    // chosen at runtime
+    pub(crate) fn read_line_with<O, C, S, H>(
+        self,
+        overrider: Option<&O>,
+        completer: Option<&C>,
+        suggester: Option<&S>,
+        handler: Option<&H>,
+    ) -> Result<Outcome, Error>
+    where
+        O: EditorOverrider + ?Sized,
+        C: Completer + ?Sized,
+        S: Suggester + ?Sized,
+        H: Handler + ?Sized,
+    {
+        super::read_line(
+            self.prompt.as_deref(),
+            self.buffer,
+            &self.options,
+            overrider,
+            completer,
+            suggester,
+            handler,
+        )
+    }
 }
 
 impl Default for Prompt {
@@ -369,15 +649,14 @@ impl<S: ToString> std::convert::From<S> for Prompt {
         Self {
             prompt: Some(s.to_string()),
             buffer: None,
-            erase_after_read: false,
-            display_suggestion_options: true,
+            options: Options::new(),
         }
     }
 }
 
 pub struct WithOverrider<O, B>
 where
-    O: Overrider,
+    O: EditorOverrider,
     B: Builder,
 {
     base: B,
@@ -402,9 +681,18 @@ where
     suggester: S,
 }
 
+pub struct WithHandler<H, B>
+where
+    H: Handler,
+    B: Builder,
+{
+    base: B,
+    handler: H,
+}
+
 pub struct WithRefOverrider<'o, O, B>
 where
-    O: Overrider + ?Sized,
+    O: EditorOverrider + ?Sized,
     B: Builder,
 {
     base: B,
@@ -429,6 +717,15 @@ where
     suggester: &'s S,
 }
 
+pub struct WithRefHandler<'h, H, B>
+where
+    H: Handler + ?Sized,
+    B: Builder,
+{
+    base: B,
+    handler: &'h H,
+}
+
 impl Builder for Prompt {
     fn buffer(mut self, buffer: Buffer) -> Self {
         self.buffer = Some(buffer);
@@ -436,23 +733,62 @@ impl Builder for Prompt {
     }
 
     fn erase_after_read(mut self, erase_after_read: bool) -> Self {
-        self.erase_after_read = erase_after_read;
+        self.options.erase_after_read = erase_after_read;
         self
     }
 
     fn display_suggestion_options(mut self, display_suggestion_options: bool) -> Self {
-        self.display_suggestion_options = display_suggestion_options;
+        self.options.display_suggestion_options = display_suggestion_options;
+        self
+    }
+
+    fn suggestion_options_layout(mut self, suggestion_options_layout: Layout) -> Self {
+        self.options.suggestion_options_layout = suggestion_options_layout;
+        self
+    }
+
+    fn suggestion_options_height(mut self, suggestion_options_height: usize) -> Self {
+        self.options.suggestion_options_height = Some(suggestion_options_height);
+        self
+    }
+
+    fn narrow_suggestions(mut self, narrow_suggestions: bool) -> Self {
+        self.options.narrow_suggestions = narrow_suggestions;
+        self
+    }
+
+    fn tab_behavior(mut self, tab_behavior: TabBehavior) -> Self {
+        self.options.tab_behavior = tab_behavior;
+        self
+    }
+
+    fn constraints(mut self, constraints: Constraints) -> Self {
+        self.options.constraints = constraints;
+        self
+    }
+
+    fn placeholder<S: Into<String>>(mut self, placeholder: S) -> Self {
+        self.options.placeholder = Some(placeholder.into());
+        self
+    }
+
+    fn history<I, S>(mut self, history: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.options.history = history.into_iter().map(Into::into).collect();
         self
     }
 
     impl_builder!(extensions);
 
     fn read_line(self) -> Result<Outcome, Error> {
-        super::read_line::<Dummy, Dummy, Dummy>(
+        super::read_line::<Dummy, Dummy, Dummy, Dummy>(
             self.prompt.as_deref(),
             self.buffer,
-            self.erase_after_read,
-            self.display_suggestion_options,
+            &self.options,
+            None,
             None,
             None,
             None,
@@ -462,15 +798,20 @@ impl Builder for Prompt {
 
 impl<T, B> Builder for WithOverrider<T, B>
 where
-    T: Overrider,
+    T: EditorOverrider,
     B: Builder,
 {
     impl_builder!(base);
     impl_builder!(extensions);
 
     fn read_line(self) -> Result<Outcome, Error> {
-        self.base
-            .chain_read_line::<T, Dummy, Dummy>(Some(&self.overrider), None, None)
+        self.base.chain_read_line::<_, T, Dummy, Dummy, Dummy>(
+            Some(&self.overrider),
+            None,
+            None,
+            None,
+            &accept,
+        )
     }
 }
 
@@ -483,8 +824,13 @@ where
     impl_builder!(extensions);
 
     fn read_line(self) -> Result<Outcome, Error> {
-        self.base
-            .chain_read_line::<Dummy, T, Dummy>(None, Some(&self.completer), None)
+        self.base.chain_read_line::<_, Dummy, T, Dummy, Dummy>(
+            None,
+            Some(&self.completer),
+            None,
+            None,
+            &accept,
+        )
     }
 }
 
@@ -497,22 +843,51 @@ where
     impl_builder!(extensions);
 
     fn read_line(self) -> Result<Outcome, Error> {
-        self.base
-            .chain_read_line::<Dummy, Dummy, T>(None, None, Some(&self.suggester))
+        self.base.chain_read_line::<_, Dummy, Dummy, T, Dummy>(
+            None,
+            None,
+            Some(&self.suggester),
+            None,
+            &accept,
+        )
+    }
+}
+
+impl<T, B> Builder for WithHandler<T, B>
+where
+    T: Handler,
+    B: Builder,
+{
+    impl_builder!(base);
+    impl_builder!(extensions);
+
+    fn read_line(self) -> Result<Outcome, Error> {
+        self.base.chain_read_line::<_, Dummy, Dummy, Dummy, T>(
+            None,
+            None,
+            None,
+            Some(&self.handler),
+            &accept,
+        )
     }
 }
 
 impl<T, B> Builder for WithRefOverrider<'_, T, B>
 where
-    T: Overrider + ?Sized,
+    T: EditorOverrider + ?Sized,
     B: Builder,
 {
     impl_builder!(base);
     impl_builder!(extensions);
 
     fn read_line(self) -> Result<Outcome, Error> {
-        self.base
-            .chain_read_line::<T, Dummy, Dummy>(Some(self.overrider), None, None)
+        self.base.chain_read_line::<_, T, Dummy, Dummy, Dummy>(
+            Some(self.overrider),
+            None,
+            None,
+            None,
+            &accept,
+        )
     }
 }
 
@@ -525,8 +900,13 @@ where
     impl_builder!(extensions);
 
     fn read_line(self) -> Result<Outcome, Error> {
-        self.base
-            .chain_read_line::<Dummy, T, Dummy>(None, Some(self.completer), None)
+        self.base.chain_read_line::<_, Dummy, T, Dummy, Dummy>(
+            None,
+            Some(self.completer),
+            None,
+            None,
+            &accept,
+        )
     }
 }
 
@@ -539,56 +919,88 @@ where
     impl_builder!(extensions);
 
     fn read_line(self) -> Result<Outcome, Error> {
-        self.base
-            .chain_read_line::<Dummy, Dummy, T>(None, None, Some(self.suggester))
+        self.base.chain_read_line::<_, Dummy, Dummy, T, Dummy>(
+            None,
+            None,
+            Some(self.suggester),
+            None,
+            &accept,
+        )
+    }
+}
+
+impl<T, B> Builder for WithRefHandler<'_, T, B>
+where
+    T: Handler + ?Sized,
+    B: Builder,
+{
+    impl_builder!(base);
+    impl_builder!(extensions);
+
+    fn read_line(self) -> Result<Outcome, Error> {
+        self.base.chain_read_line::<_, Dummy, Dummy, Dummy, T>(
+            None,
+            None,
+            None,
+            Some(self.handler),
+            &accept,
+        )
     }
 }
 
 impl ChainedLineReader for Prompt {
-    fn chain_read_line<O, C, S>(
+    fn chain_read_line<P, O, C, S, H>(
         self,
         overrider: Option<&O>,
         completer: Option<&C>,
         suggester: Option<&S>,
-    ) -> Result<Outcome, Error>
+        handler: Option<&H>,
+        parse: &dyn Fn(&str) -> Result<P, String>,
+    ) -> Result<Outcome<P>, Error>
     where
-        O: Overrider + ?Sized,
+        O: EditorOverrider + ?Sized,
         C: Completer + ?Sized,
         S: Suggester + ?Sized,
+        H: Handler + ?Sized,
     {
-        super::read_line(
+        super::read(
             self.prompt.as_deref(),
             self.buffer,
-            self.erase_after_read,
-            self.display_suggestion_options,
+            self.options,
             overrider,
             completer,
             suggester,
+            handler,
+            parse,
         )
     }
 }
 
 impl<T, B> ChainedLineReader for WithOverrider<T, B>
 where
-    T: Overrider,
+    T: EditorOverrider,
     B: Builder,
 {
-    fn chain_read_line<O, C, S>(
+    fn chain_read_line<P, O, C, S, H>(
         self,
         overrider: Option<&O>,
         completer: Option<&C>,
         suggester: Option<&S>,
-    ) -> Result<Outcome, Error>
+        handler: Option<&H>,
+        parse: &dyn Fn(&str) -> Result<P, String>,
+    ) -> Result<Outcome<P>, Error>
     where
-        O: Overrider + ?Sized,
+        O: EditorOverrider + ?Sized,
         C: Completer + ?Sized,
         S: Suggester + ?Sized,
+        H: Handler + ?Sized,
     {
         if overrider.is_some() {
-            self.base.chain_read_line(overrider, completer, suggester)
+            self.base
+                .chain_read_line(overrider, completer, suggester, handler, parse)
         } else {
             self.base
-                .chain_read_line(Some(&self.overrider), completer, suggester)
+                .chain_read_line(Some(&self.overrider), completer, suggester, handler, parse)
         }
     }
 }
@@ -598,22 +1010,26 @@ where
     T: Completer,
     B: Builder,
 {
-    fn chain_read_line<O, C, S>(
+    fn chain_read_line<P, O, C, S, H>(
         self,
         overrider: Option<&O>,
         completer: Option<&C>,
         suggester: Option<&S>,
-    ) -> Result<Outcome, Error>
+        handler: Option<&H>,
+        parse: &dyn Fn(&str) -> Result<P, String>,
+    ) -> Result<Outcome<P>, Error>
     where
-        O: Overrider + ?Sized,
+        O: EditorOverrider + ?Sized,
         C: Completer + ?Sized,
         S: Suggester + ?Sized,
+        H: Handler + ?Sized,
     {
         if completer.is_some() {
-            self.base.chain_read_line(overrider, completer, suggester)
+            self.base
+                .chain_read_line(overrider, completer, suggester, handler, parse)
         } else {
             self.base
-                .chain_read_line(overrider, Some(&self.completer), suggester)
+                .chain_read_line(overrider, Some(&self.completer), suggester, handler, parse)
         }
     }
 }
@@ -623,47 +1039,84 @@ where
     T: Suggester,
     B: Builder,
 {
-    fn chain_read_line<O, C, S>(
+    fn chain_read_line<P, O, C, S, H>(
         self,
         overrider: Option<&O>,
         completer: Option<&C>,
         suggester: Option<&S>,
-    ) -> Result<Outcome, Error>
+        handler: Option<&H>,
+        parse: &dyn Fn(&str) -> Result<P, String>,
+    ) -> Result<Outcome<P>, Error>
     where
-        O: Overrider + ?Sized,
+        O: EditorOverrider + ?Sized,
         C: Completer + ?Sized,
         S: Suggester + ?Sized,
+        H: Handler + ?Sized,
     {
         if suggester.is_some() {
-            self.base.chain_read_line(overrider, completer, suggester)
+            self.base
+                .chain_read_line(overrider, completer, suggester, handler, parse)
+        } else {
+            self.base
+                .chain_read_line(overrider, completer, Some(&self.suggester), handler, parse)
+        }
+    }
+}
+
+impl<T, B> ChainedLineReader for WithHandler<T, B>
+where
+    T: Handler,
+    B: Builder,
+{
+    fn chain_read_line<P, O, C, S, H>(
+        self,
+        overrider: Option<&O>,
+        completer: Option<&C>,
+        suggester: Option<&S>,
+        handler: Option<&H>,
+        parse: &dyn Fn(&str) -> Result<P, String>,
+    ) -> Result<Outcome<P>, Error>
+    where
+        O: EditorOverrider + ?Sized,
+        C: Completer + ?Sized,
+        S: Suggester + ?Sized,
+        H: Handler + ?Sized,
+    {
+        if handler.is_some() {
+            self.base
+                .chain_read_line(overrider, completer, suggester, handler, parse)
         } else {
             self.base
-                .chain_read_line(overrider, completer, Some(&self.suggester))
+                .chain_read_line(overrider, completer, suggester, Some(&self.handler), parse)
         }
     }
 }
 
 impl<T, B> ChainedLineReader for WithRefOverrider<'_, T, B>
 where
-    T: Overrider + ?Sized,
+    T: EditorOverrider + ?Sized,
     B: Builder,
 {
-    fn chain_read_line<O, C, S>(
+    fn chain_read_line<P, O, C, S, H>(
         self,
         overrider: Option<&O>,
         completer: Option<&C>,
         suggester: Option<&S>,
-    ) -> Result<Outcome, Error>
+        handler: Option<&H>,
+        parse: &dyn Fn(&str) -> Result<P, String>,
+    ) -> Result<Outcome<P>, Error>
     where
-        O: Overrider + ?Sized,
+        O: EditorOverrider + ?Sized,
         C: Completer + ?Sized,
         S: Suggester + ?Sized,
+        H: Handler + ?Sized,
     {
         if overrider.is_some() {
-            self.base.chain_read_line(overrider, completer, suggester)
+            self.base
+                .chain_read_line(overrider, completer, suggester, handler, parse)
         } else {
             self.base
-                .chain_read_line(Some(self.overrider), completer, suggester)
+                .chain_read_line(Some(self.overrider), completer, suggester, handler, parse)
         }
     }
 }
@@ -673,22 +1126,26 @@ where
     T: Completer + ?Sized,
     B: Builder,
 {
-    fn chain_read_line<O, C, S>(
+    fn chain_read_line<P, O, C, S, H>(
         self,
         overrider: Option<&O>,
         completer: Option<&C>,
         suggester: Option<&S>,
-    ) -> Result<Outcome, Error>
+        handler: Option<&H>,
+        parse: &dyn Fn(&str) -> Result<P, String>,
+    ) -> Result<Outcome<P>, Error>
     where
-        O: Overrider + ?Sized,
+        O: EditorOverrider + ?Sized,
         C: Completer + ?Sized,
         S: Suggester + ?Sized,
+        H: Handler + ?Sized,
     {
         if completer.is_some() {
-            self.base.chain_read_line(overrider, completer, suggester)
+            self.base
+                .chain_read_line(overrider, completer, suggester, handler, parse)
         } else {
             self.base
-                .chain_read_line(overrider, Some(self.completer), suggester)
+                .chain_read_line(overrider, Some(self.completer), suggester, handler, parse)
         }
     }
 }
@@ -698,22 +1155,55 @@ where
     T: Suggester + ?Sized,
     B: Builder,
 {
-    fn chain_read_line<O, C, S>(
+    fn chain_read_line<P, O, C, S, H>(
         self,
         overrider: Option<&O>,
         completer: Option<&C>,
         suggester: Option<&S>,
-    ) -> Result<Outcome, Error>
+        handler: Option<&H>,
+        parse: &dyn Fn(&str) -> Result<P, String>,
+    ) -> Result<Outcome<P>, Error>
     where
-        O: Overrider + ?Sized,
+        O: EditorOverrider + ?Sized,
         C: Completer + ?Sized,
         S: Suggester + ?Sized,
+        H: Handler + ?Sized,
     {
         if suggester.is_some() {
-            self.base.chain_read_line(overrider, completer, suggester)
+            self.base
+                .chain_read_line(overrider, completer, suggester, handler, parse)
+        } else {
+            self.base
+                .chain_read_line(overrider, completer, Some(self.suggester), handler, parse)
+        }
+    }
+}
+
+impl<T, B> ChainedLineReader for WithRefHandler<'_, T, B>
+where
+    T: Handler + ?Sized,
+    B: Builder,
+{
+    fn chain_read_line<P, O, C, S, H>(
+        self,
+        overrider: Option<&O>,
+        completer: Option<&C>,
+        suggester: Option<&S>,
+        handler: Option<&H>,
+        parse: &dyn Fn(&str) -> Result<P, String>,
+    ) -> Result<Outcome<P>, Error>
+    where
+        O: EditorOverrider + ?Sized,
+        C: Completer + ?Sized,
+        S: Suggester + ?Sized,
+        H: Handler + ?Sized,
+    {
+        if handler.is_some() {
+            self.base
+                .chain_read_line(overrider, completer, suggester, handler, parse)
         } else {
             self.base
-                .chain_read_line(overrider, completer, Some(self.suggester))
+                .chain_read_line(overrider, completer, suggester, Some(self.handler), parse)
         }
     }
 }
@@ -729,9 +1219,9 @@ where
 impl<'a, F, R> Completer for Closure<'a, F, Option<R>>
 where
     F: Fn(&Buffer) -> Option<R>,
-    R: Into<std::borrow::Cow<'a, str>>,
+    R: Into<Completion<'a>>,
 {
-    fn complete_for(&self, buffer: &Buffer) -> Option<std::borrow::Cow<'_, str>> {
+    fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
         (self.closure)(buffer).map(Into::into)
     }
 }
@@ -739,14 +1229,22 @@ where
 impl<'a, F, R> Suggester for Closure<'a, F, Vec<R>>
 where
     F: Fn(&Buffer) -> Vec<R>,
-    R: Into<std::borrow::Cow<'a, str>>,
+    R: Into<Suggestion<'a>>,
 {
-    fn suggest_for(&self, buffer: &Buffer) -> Vec<std::borrow::Cow<'_, str>> {
+    fn suggest_for(&self, buffer: &Buffer) -> Vec<Suggestion<'_>> {
         (self.closure)(buffer).into_iter().map(Into::into).collect()
     }
 }
 
-struct Dummy;
+// The parser of `read_line`, which accepts any text
+//
+// Allowed because it is passed where any parser is
+#[allow(clippy::unnecessary_wraps)]
+fn accept(text: &str) -> Result<String, String> {
+    Ok(text.to_owned())
+}
+
+pub(super) struct Dummy;
 
 impl Overrider for Dummy {
     fn override_for(&self, _: crate::actions::Event, _: &Buffer) -> Option<crate::actions::Action> {
@@ -755,13 +1253,19 @@ impl Overrider for Dummy {
 }
 
 impl Completer for Dummy {
-    fn complete_for(&self, _: &Buffer) -> Option<std::borrow::Cow<'_, str>> {
+    fn complete_for(&self, _: &Buffer) -> Option<Completion<'_>> {
         unimplemented!()
     }
 }
 
 impl Suggester for Dummy {
-    fn suggest_for(&self, _: &Buffer) -> Vec<std::borrow::Cow<'_, str>> {
+    fn suggest_for(&self, _: &Buffer) -> Vec<Suggestion<'_>> {
+        unimplemented!()
+    }
+}
+
+impl Handler for Dummy {
+    fn handle(&self, _: u32, _: &mut dyn Editor) -> Action {
         unimplemented!()
     }
 }
@@ -782,13 +1286,17 @@ mod test {
         );
     }
 
+    // Allowed because the mocks need to implement every hook
+    #[allow(clippy::too_many_lines)]
     #[test]
     fn last_hook_is_used() {
         use super::{
-            Action, Buffer, Closure, Completer, Dummy, Error, Event, Outcome, Overrider, Suggester,
-            WithCompleter, WithOverrider, WithRefCompleter, WithRefOverrider, WithRefSuggester,
-            WithSuggester,
+            accept, Action, Buffer, Closure, Completer, Completion, Constraints, Dummy, Editor,
+            EditorOverrider, Error, Event, Handler, Layout, Outcome, Overrider, Suggester,
+            TabBehavior, WithCompleter, WithHandler, WithOverrider, WithRefCompleter,
+            WithRefHandler, WithRefOverrider, WithRefSuggester, WithSuggester,
         };
+        use crate::actions::Direction;
         use crossterm::event::KeyCode::Tab;
 
         struct MockBuilder;
@@ -805,6 +1313,38 @@ mod test {
                 unimplemented!()
             }
 
+            fn suggestion_options_layout(self, _: Layout) -> Self {
+                unimplemented!()
+            }
+
+            fn suggestion_options_height(self, _: usize) -> Self {
+                unimplemented!()
+            }
+
+            fn narrow_suggestions(self, _: bool) -> Self {
+                unimplemented!()
+            }
+
+            fn tab_behavior(self, _: TabBehavior) -> Self {
+                unimplemented!()
+            }
+
+            fn constraints(self, _: Constraints) -> Self {
+                unimplemented!()
+            }
+
+            fn placeholder<S: Into<String>>(self, _: S) -> Self {
+                unimplemented!()
+            }
+
+            fn history<I, S>(self, _: I) -> Self
+            where
+                I: IntoIterator<Item = S>,
+                S: Into<String>,
+            {
+                unimplemented!()
+            }
+
             fn read_line(self) -> Result<Outcome, Error> {
                 unimplemented!()
             }
@@ -813,23 +1353,26 @@ mod test {
         }
 
         impl ChainedLineReader for MockBuilder {
-            fn chain_read_line<O, C, S>(
+            fn chain_read_line<P, O, C, S, H>(
                 self,
                 overrider: Option<&O>,
                 completer: Option<&C>,
                 suggester: Option<&S>,
-            ) -> Result<Outcome, Error>
+                handler: Option<&H>,
+                parse: &dyn Fn(&str) -> Result<P, String>,
+            ) -> Result<Outcome<P>, Error>
             where
-                O: Overrider + ?Sized,
+                O: EditorOverrider + ?Sized,
                 C: Completer + ?Sized,
                 S: Suggester + ?Sized,
+                H: Handler + ?Sized,
             {
                 assert_eq!(
                     overrider
                         .unwrap()
-                        .override_for(Event::from(Tab), &Buffer::new())
+                        .override_with(Event::from(Tab), &mut MockEditor(Buffer::new()))
                         .unwrap(),
-                    Action::Accept
+                    vec![Action::Accept]
                 );
                 assert_eq!(
                     completer.unwrap().complete_for(&Buffer::from("-")).unwrap(),
@@ -839,7 +1382,12 @@ mod test {
                     suggester.unwrap().suggest_for(&Buffer::from("-"))[0],
                     "-expected"
                 );
-                Ok(Outcome::Accepted(String::new()))
+                assert_eq!(
+                    handler.unwrap().handle(0, &mut MockEditor(Buffer::new())),
+                    Action::Accept
+                );
+                assert!(parse("text").is_ok());
+                Ok(Outcome::Eof)
             }
         }
 
@@ -851,6 +1399,42 @@ mod test {
             }
         }
 
+        struct MockHandler;
+
+        impl Handler for MockHandler {
+            fn handle(&self, _: u32, _: &mut dyn Editor) -> Action {
+                Action::Cancel
+            }
+        }
+
+        struct MockEditor(Buffer);
+
+        impl Editor for MockEditor {
+            fn buffer(&self) -> &Buffer {
+                &self.0
+            }
+
+            fn buffer_mut(&mut self) -> &mut Buffer {
+                &mut self.0
+            }
+
+            fn is_suggesting(&self) -> bool {
+                false
+            }
+
+            fn completion(&self) -> Option<&Completion<'_>> {
+                None
+            }
+
+            fn suggest(&mut self, _: Direction) {}
+
+            fn cancel_suggestion(&mut self) {}
+
+            fn history_position(&self) -> Option<usize> {
+                None
+            }
+        }
+
         MockBuilder {}
             .overrider_ref(&MockOverrider)
             .overrider(MockOverrider)
@@ -863,7 +1447,10 @@ mod test {
             .suggester(["-unexpected"])
             .suggester_fn(|_| vec!["-unexpected"])
             .suggester_ref(&vec!["-expected"])
-            .chain_read_line::<Dummy, Dummy, Dummy>(None, None, None)
+            .handler_ref(&MockHandler)
+            .handler(MockHandler)
+            .handler_fn(|_, _| Action::Accept)
+            .chain_read_line::<String, Dummy, Dummy, Dummy, Dummy>(None, None, None, None, &accept)
             .unwrap();
     }
 }
@@ -0,0 +1,187 @@
+use super::{Action, Buffer, Outcome, Writer};
+
+use crate::actions::default_action;
+use crate::Error;
+
+/// A yes/no question, answered with a single key.
+///
+/// Pressing `y` or `n`, in either case, answers right away, without `Enter`. Pressing `Enter`
+/// picks the default answer, if there is one. The prompt text is followed by a hint of the keys,
+/// with the default answer in upper case, e.g. `[Y/n]`.
+///
+/// As with [`read_line`], `Esc` cancels, `Ctrl-C` interrupts and `Ctrl-D` ends the input.
+///
+/// # Example
+///
+/// ```no_run
+/// use rucline::Outcome::Accepted;
+/// use rucline::prompt::Confirm;
+///
+/// if let Ok(Accepted(true)) = Confirm::from("Delete file? ").default_answer(false).read() {
+///     println!("Deleted");
+/// }
+/// ```
+///
+/// [`read_line`]: fn.read_line.html
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Confirm {
+    prompt: Option<String>,
+    default_answer: Option<bool>,
+    erase_after_read: bool,
+}
+
+impl Confirm {
+    /// Creates a new [`Confirm`] with no prompt text and no default answer.
+    ///
+    /// [`Confirm`]: struct.Confirm.html
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            prompt: None,
+            default_answer: None,
+            erase_after_read: false,
+        }
+    }
+
+    /// Sets the answer picked by pressing `Enter`.
+    ///
+    /// Without a default answer, `Enter` is ignored.
+    #[must_use]
+    pub fn default_answer(mut self, default_answer: bool) -> Self {
+        self.default_answer = Some(default_answer);
+        self
+    }
+
+    /// Controls if the prompt should be erased after the question is answered.
+    ///
+    /// Defaults to `false`.
+    #[must_use]
+    pub fn erase_after_read(mut self, erase_after_read: bool) -> Self {
+        self.erase_after_read = erase_after_read;
+        self
+    }
+
+    /// Consumes this [`Confirm`] to ask the question, blocking until it is answered.
+    ///
+    /// # Return
+    /// * [`Outcome`] - Either [`Accepted`] containing the answer, [`Canceled`] or [`Interrupted`]
+    ///   containing an empty [`buffer`], or [`Eof`].
+    ///
+    /// # Errors
+    /// * [`Error`] - If an error occurred while reading the user input.
+    ///
+    /// [`Accepted`]: enum.Outcome.html#variant.Accepted
+    /// [`Canceled`]: enum.Outcome.html#variant.Canceled
+    /// [`Confirm`]: struct.Confirm.html
+    /// [`Eof`]: enum.Outcome.html#variant.Eof
+    /// [`Error`]: ../enum.Error.html
+    /// [`Interrupted`]: enum.Outcome.html#variant.Interrupted
+    /// [`Outcome`]: enum.Outcome.html
+    /// [`buffer`]: ../buffer/struct.Buffer.html
+    pub fn read(self) -> Result<Outcome<bool>, Error> {
+        let prompt = format!(
+            "{}{} ",
+            self.prompt.as_deref().unwrap_or_default(),
+            hint(self.default_answer)
+        );
+        let mut writer = Writer::new(self.erase_after_read, Some(&prompt), None)?;
+        writer.print(&Buffer::new(), None)?;
+
+        loop {
+            if let crossterm::event::Event::Key(e) = crossterm::event::read()? {
+                if let Some(outcome) =
+                    answer(default_action(e, &Buffer::new()), self.default_answer)
+                {
+                    if let Outcome::Accepted(answer) = outcome {
+                        writer.print(&Buffer::from(if answer { "yes" } else { "no" }), None)?;
+                    }
+                    return Ok(outcome);
+                }
+            }
+        }
+    }
+}
+
+impl Default for Confirm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: ToString> std::convert::From<S> for Confirm {
+    fn from(s: S) -> Self {
+        Self {
+            prompt: Some(s.to_string()),
+            ..Self::new()
+        }
+    }
+}
+
+fn hint(default_answer: Option<bool>) -> &'static str {
+    match default_answer {
+        Some(true) => "[Y/n]",
+        Some(false) => "[y/N]",
+        None => "[y/n]",
+    }
+}
+
+// Returns the outcome if the action answers the question
+fn answer(action: Action, default_answer: Option<bool>) -> Option<Outcome<bool>> {
+    match action {
+        Action::Write('y' | 'Y') => Some(Outcome::Accepted(true)),
+        Action::Write('n' | 'N') => Some(Outcome::Accepted(false)),
+        Action::Accept => default_answer.map(Outcome::Accepted),
+        Action::Cancel => Some(Outcome::Canceled(Buffer::new())),
+        Action::Eof => Some(Outcome::Eof),
+        Action::Interrupt => Some(Outcome::Interrupted(Buffer::new())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{answer, hint, Action, Outcome};
+
+    #[test]
+    fn hint_shows_the_default_in_upper_case() {
+        assert_eq!(hint(Some(true)), "[Y/n]");
+        assert_eq!(hint(Some(false)), "[y/N]");
+        assert_eq!(hint(None), "[y/n]");
+    }
+
+    #[test]
+    fn answer_with_a_single_key() {
+        assert_eq!(answer(Action::Write('y'), None).unwrap().some(), Some(true));
+        assert_eq!(
+            answer(Action::Write('N'), Some(true)).unwrap().some(),
+            Some(false)
+        );
+        assert!(answer(Action::Write('x'), Some(true)).is_none());
+    }
+
+    #[test]
+    fn enter_picks_the_default() {
+        assert_eq!(
+            answer(Action::Accept, Some(true)).unwrap().some(),
+            Some(true)
+        );
+        assert_eq!(
+            answer(Action::Accept, Some(false)).unwrap().some(),
+            Some(false)
+        );
+        assert!(answer(Action::Accept, None).is_none());
+    }
+
+    #[test]
+    fn cancel_and_end_without_an_answer() {
+        assert!(matches!(
+            answer(Action::Cancel, Some(true)),
+            Some(Outcome::Canceled(_))
+        ));
+        assert!(matches!(
+            answer(Action::Interrupt, Some(true)),
+            Some(Outcome::Interrupted(_))
+        ));
+        assert!(answer(Action::Eof, Some(true)).unwrap().is_eof());
+    }
+}
@@ -0,0 +1,193 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Restrictions on what can go into the buffer.
+///
+/// The restrictions apply to everything that goes into the buffer: typed characters, accepted
+/// completions and suggestions, pasted text, externally edited text and recalled lines.
+/// Characters that are not allowed are dropped, and text beyond the maximum length is cut off.
+/// If anything is rejected, the terminal bell can be rung.
+///
+/// # Example
+///
+/// ```no_run
+/// use rucline::prompt::{Builder, Constraints, Prompt};
+///
+/// let outcome = Prompt::from("Color: #")
+///     .constraints(
+///         Constraints::new()
+///             .max_length(6)
+///             .allowed(|c| c.is_ascii_hexdigit())
+///             .uppercase(true)
+///             .bell(true),
+///     )
+///     .read_line();
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Constraints {
+    max_length: Option<usize>,
+    allowed: Option<fn(char) -> bool>,
+    uppercase: bool,
+    bell: bool,
+}
+
+impl Constraints {
+    /// Creates a new [`Constraints`] that allows anything.
+    ///
+    /// [`Constraints`]: struct.Constraints.html
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            max_length: None,
+            allowed: None,
+            uppercase: false,
+            bell: false,
+        }
+    }
+
+    /// Limits the buffer to `max_length` graphemes.
+    #[must_use]
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Only allows the characters for which `allowed` returns `true`.
+    ///
+    /// If forcing upper case, the predicate is checked after converting the characters.
+    #[must_use]
+    pub fn allowed(mut self, allowed: fn(char) -> bool) -> Self {
+        self.allowed = Some(allowed);
+        self
+    }
+
+    /// Converts any lower case characters to upper case.
+    #[must_use]
+    pub fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Rings the terminal bell when input is rejected.
+    #[must_use]
+    pub fn bell(mut self, bell: bool) -> Self {
+        self.bell = bell;
+        self
+    }
+
+    // The part of `text` that may be inserted into a buffer of `length` graphemes, and whether
+    // anything was rejected
+    pub(super) fn filter(&self, text: &str, length: usize) -> (String, bool) {
+        let mut filtered = String::with_capacity(text.len());
+        let mut rejected = false;
+
+        for c in text.chars() {
+            let converted = if self.uppercase {
+                c.to_uppercase().collect()
+            } else {
+                String::from(c)
+            };
+
+            for c in converted.chars() {
+                if self.allowed.is_none_or(|allowed| allowed(c)) {
+                    filtered.push(c);
+                } else {
+                    rejected = true;
+                }
+            }
+        }
+
+        if let Some(max_length) = self.max_length {
+            let room = max_length.saturating_sub(length);
+            if let Some((end, _)) = filtered.grapheme_indices(true).nth(room) {
+                filtered.truncate(end);
+                rejected = true;
+            }
+        }
+
+        (filtered, rejected)
+    }
+
+    // The part of `text` that may replace `span` of `buffer`, and whether anything was rejected
+    pub(super) fn filter_replacement(
+        &self,
+        text: &str,
+        buffer: &str,
+        span: std::ops::Range<usize>,
+    ) -> (String, bool) {
+        let length = buffer.graphemes(true).count()
+            - buffer
+                .get(span)
+                .map_or(0, |replaced| replaced.graphemes(true).count());
+        self.filter(text, length)
+    }
+
+    pub(super) fn rings_bell(&self) -> bool {
+        self.bell
+    }
+}
+
+impl Default for Constraints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for Constraints {
+    fn eq(&self, other: &Self) -> bool {
+        let allowed = match (self.allowed, other.allowed) {
+            (Some(a), Some(b)) => a as usize == b as usize,
+            (a, b) => a.is_none() && b.is_none(),
+        };
+
+        allowed
+            && self.max_length == other.max_length
+            && self.uppercase == other.uppercase
+            && self.bell == other.bell
+    }
+}
+
+impl Eq for Constraints {}
+
+#[cfg(test)]
+mod test {
+    use super::Constraints;
+
+    #[test]
+    fn anything_goes_by_default() {
+        assert_eq!(
+            Constraints::new().filter("any text", 1000),
+            (String::from("any text"), false)
+        );
+    }
+
+    #[test]
+    fn drop_characters_that_are_not_allowed() {
+        let hex = Constraints::new().allowed(|c| c.is_ascii_hexdigit());
+        assert_eq!(hex.filter("c0ffee", 0), (String::from("c0ffee"), false));
+        assert_eq!(hex.filter("c0 fg", 0), (String::from("c0f"), true));
+    }
+
+    #[test]
+    fn check_the_upper_case_characters() {
+        let upper_hex = Constraints::new()
+            .uppercase(true)
+            .allowed(|c| c.is_ascii_digit() || ('A'..='F').contains(&c));
+        assert_eq!(
+            upper_hex.filter("c0ffee", 0),
+            (String::from("C0FFEE"), false)
+        );
+    }
+
+    #[test]
+    fn cut_off_beyond_the_max_length() {
+        let short = Constraints::new().max_length(4);
+        assert_eq!(short.filter("ab", 1), (String::from("ab"), false));
+        assert_eq!(short.filter("abc", 2), (String::from("ab"), true));
+        assert_eq!(short.filter("a", 4), (String::new(), true));
+        assert_eq!(short.filter("a", 6), (String::new(), true));
+        assert_eq!(
+            short.filter("e\u{301}e\u{301}x", 2),
+            (String::from("e\u{301}e\u{301}"), true)
+        );
+    }
+}
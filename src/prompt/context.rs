@@ -1,4 +1,7 @@
-use super::{Buffer, Completer, Direction, Range, Scope, Suggester, Writer};
+use super::{
+    Action, Buffer, Completer, Completion, Constraints, Direction, Editor, Handler, Layout,
+    Options, Range, Scope, Suggester, Suggestion, TabBehavior, Writer,
+};
 
 use crate::Error;
 
@@ -10,10 +13,18 @@ where
     writer: Writer,
     buffer: Buffer,
     completer: Option<&'c C>,
-    completion: Option<std::borrow::Cow<'c, str>>,
+    completion: Option<Completion<'c>>,
     suggester: Option<&'s S>,
     suggestions: Option<Suggestions<'s>>,
-    display_suggestion_options: bool,
+    // The direction of a suggestion request that is waiting for a pending suggester
+    pending_suggestion: Option<Direction>,
+    pending_completion: bool,
+    // Whether the line was changed through the `Editor` since it was last rendered
+    edited: bool,
+    // The entry of the history in the buffer, if any, and the line that was typed before it
+    history_position: Option<usize>,
+    draft: Buffer,
+    options: Options,
 }
 
 impl<'c, 's, C, S> Context<'c, 's, C, S>
@@ -22,21 +33,29 @@ where
     S: Suggester + ?Sized,
 {
     pub(super) fn new(
-        erase_on_drop: bool,
-        display_suggestion_options: bool,
+        options: Options,
         prompt: Option<&str>,
         buffer: Option<Buffer>,
         completer: Option<&'c C>,
         suggester: Option<&'s S>,
     ) -> Result<Self, Error> {
         Ok(Self {
-            writer: Writer::new(erase_on_drop, prompt)?,
-            buffer: buffer.unwrap_or_else(Buffer::new),
+            writer: Writer::new(
+                options.erase_after_read,
+                prompt,
+                options.placeholder.as_deref(),
+            )?,
+            buffer: buffer.unwrap_or_default(),
             completer,
             completion: None,
             suggester,
             suggestions: None,
-            display_suggestion_options,
+            pending_suggestion: None,
+            pending_completion: false,
+            edited: false,
+            history_position: None,
+            draft: Buffer::new(),
+            options,
         })
     }
 
@@ -45,114 +64,392 @@ where
         self.buffer.to_string()
     }
 
+    pub(super) fn finish(&mut self) -> Result<(), Error> {
+        self.try_take_suggestion();
+        self.completion = None;
+        self.writer.hide_placeholder();
+        self.print()
+    }
+
+    // Renders why the buffer could not be accepted, until the next print
+    pub(super) fn reject(&mut self, message: &str) -> Result<(), Error> {
+        self.print()?;
+        super::writer::print_error(message)
+    }
+
     pub(super) fn print(&mut self) -> Result<(), Error> {
-        self.writer.print(&self.buffer, self.completion.as_deref())
+        self.writer
+            .print(&self.buffer, ghost(&self.buffer, self.completion.as_ref()))
     }
 
     pub(super) fn write(&mut self, c: char) -> Result<(), Error> {
+        let mut text = [0; 4];
+        self.write_str(c.encode_utf8(&mut text))
+    }
+
+    pub(super) fn paste(&mut self, text: &str) -> Result<(), Error> {
+        // The buffer is a single line
+        self.write_str(&text.lines().collect::<Vec<_>>().join(" "))
+    }
+
+    fn write_str(&mut self, text: &str) -> Result<(), Error> {
+        if self.is_narrowing() {
+            let text = self.constrain(text, 0..0)?;
+            self.buffer.write_str(&text);
+            return self.narrow();
+        }
+
         self.try_take_suggestion();
-        self.buffer.write(c);
+        let text = self.constrain(text, 0..0)?;
+        if text.is_empty() {
+            return Ok(());
+        }
+        self.buffer.write_str(&text);
         self.update_completion();
-        self.writer.print(&self.buffer, self.completion.as_deref())
+        self.print()
+    }
+
+    // The part of `text` that may replace `span` of the buffer, ringing the bell if configured and
+    // anything is rejected
+    fn constrain(&self, text: &str, span: std::ops::Range<usize>) -> Result<String, Error> {
+        let (text, rejected) =
+            self.options
+                .constraints
+                .filter_replacement(text, &self.buffer, span);
+        if rejected && self.options.constraints.rings_bell() {
+            super::writer::bell()?;
+        }
+        Ok(text)
     }
 
     pub(super) fn delete(&mut self, scope: Scope) -> Result<(), Error> {
+        if self.is_narrowing() {
+            self.buffer.delete(scope);
+            return self.narrow();
+        }
+
         self.try_take_suggestion();
         self.buffer.delete(scope);
         self.update_completion();
-        self.writer.print(&self.buffer, self.completion.as_deref())
+        self.print()
     }
 
     pub(super) fn move_cursor(&mut self, range: Range, direction: Direction) -> Result<(), Error> {
         self.try_take_suggestion();
         self.buffer.move_cursor(range, direction);
-        self.writer.print(&self.buffer, self.completion.as_deref())
+        self.print()
     }
 
-    // Allowed because using map requires a `self` borrow
-    #[allow(clippy::option_if_let_else)]
     pub(super) fn complete(&mut self, range: Range) -> Result<(), Error> {
-        self.buffer.go_to_end();
-        if let Some(completion) = &self.completion {
-            if completion.is_empty() {
-                Ok(())
-            } else {
-                self.buffer.write_range(completion, range);
-                self.update_completion();
-                self.writer.print(&self.buffer, self.completion.as_deref())
-            }
+        let Some(completion) = &self.completion else {
+            self.buffer.go_to_end();
+            return Ok(());
+        };
+
+        let span = completion.span().unwrap_or_else(|| {
+            self.buffer.go_to_end();
+            self.buffer.len()..self.buffer.len()
+        });
+
+        let Some((_, remainder)) = ghost(&self.buffer, Some(completion)) else {
+            return Ok(());
+        };
+        if remainder.is_empty() {
+            return Ok(());
+        }
+
+        // The completed part of the span is kept as given by the completer
+        let text = completion.text();
+        let mut replacement = Buffer::from(&text[..text.len() - remainder.len()]);
+        replacement.write_range(remainder, range);
+        let replacement = self.constrain(&replacement, span.clone())?;
+
+        if self.buffer.replace_range(span, &replacement).is_ok() {
+            self.update_completion();
+            self.print()
         } else {
             Ok(())
         }
     }
 
+    pub(super) fn recall(&mut self, direction: Direction) -> Result<(), Error> {
+        let position = step_history(self.history_position, direction, self.options.history.len());
+        if position == self.history_position {
+            return Ok(());
+        }
+
+        self.suggestions = None;
+        self.pending_suggestion = None;
+        let recalled = match position {
+            Some(index) => {
+                Buffer::from(self.constrain(&self.options.history[index], 0..self.buffer.len())?)
+            }
+            None => std::mem::take(&mut self.draft),
+        };
+        let replaced = std::mem::replace(&mut self.buffer, recalled);
+        if self.history_position.is_none() {
+            self.draft = replaced;
+        }
+        self.history_position = position;
+
+        self.update_completion();
+        self.print()
+    }
+
+    pub(super) fn edit_externally(&mut self) -> Result<(), Error> {
+        self.try_take_suggestion();
+        self.writer.suspend()?;
+        let edited = super::external::edit(&super::external::editor(), &self.buffer);
+        self.writer.resume()?;
+
+        // If the editor fails, the line is kept as it was
+        match edited {
+            Ok(Some(edited)) => {
+                self.buffer = self.constrain(&edited, 0..self.buffer.len())?.into();
+            }
+            Ok(None) => {}
+            Err(error) => {
+                self.update_completion();
+                return self.reject(&format!("Could not edit the line externally: {error}"));
+            }
+        }
+        self.update_completion();
+        self.print()
+    }
+
+    pub(super) fn is_completing_at_cursor(&self) -> bool {
+        ghost(&self.buffer, self.completion.as_ref()).is_some_and(|(position, remainder)| {
+            position == self.buffer.cursor() && !remainder.is_empty()
+        })
+    }
+
     fn update_completion(&mut self) {
         if let Some(completer) = self.completer {
             self.completion = completer.complete_for(self);
+            self.pending_completion = completer.is_completion_pending();
         }
     }
 
+    pub(super) fn is_pending(&self) -> bool {
+        self.pending_completion || self.pending_suggestion.is_some()
+    }
+
+    pub(super) fn refresh(&mut self) -> Result<(), Error> {
+        if self.pending_completion && !self.completer.is_some_and(Completer::is_completion_pending)
+        {
+            self.update_completion();
+            self.print_suggestions()?;
+        }
+
+        if let Some(direction) = self.pending_suggestion {
+            if !self.suggester.is_some_and(Suggester::is_suggestion_pending) {
+                self.pending_suggestion = None;
+                self.suggest(direction)?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub(super) fn suggest(&mut self, direction: Direction) -> Result<(), Error> {
-        if let Some(suggester) = &self.suggester {
-            if let Some(suggestions) = &mut self.suggestions {
-                suggestions.cycle(direction);
-                if let Some(index) = suggestions.index {
-                    self.writer
-                        .print_selected_suggestion(index, suggestions.options.as_ref())?;
-
-                    if self.display_suggestion_options {
-                        self.writer
-                            .print_suggestion_options(index, suggestions.options.as_ref())?;
-                    }
-                    return Ok(());
+        self.load_suggestions(direction);
+        self.print_suggestions()
+    }
+
+    pub(super) fn handle<H: Handler + ?Sized>(
+        &mut self,
+        handler: &H,
+        id: u32,
+    ) -> Result<Action, Error> {
+        let action = handler.handle(id, self);
+        self.edited = true;
+        self.render_edits()?;
+        Ok(action)
+    }
+
+    // Renders the changes made through the `Editor`, e.g. by an overrider
+    pub(super) fn render_edits(&mut self) -> Result<(), Error> {
+        if std::mem::take(&mut self.edited) {
+            self.update_completion();
+            self.print_suggestions()?;
+        }
+        Ok(())
+    }
+
+    fn load_suggestions(&mut self, direction: Direction) {
+        if let Some(suggestions) = &mut self.suggestions {
+            suggestions.cycle(direction);
+        } else if let Some(suggester) = self.suggester {
+            let options = suggester.suggest_for(self);
+            if options.is_empty() && suggester.is_suggestion_pending() {
+                self.pending_suggestion = Some(direction);
+            } else if self.options.tab_behavior == TabBehavior::CommonPrefix
+                && self.insert_common_prefix(&options)
+            {
+                // Only the following suggest actions list the options
+            } else if !options.is_empty() {
+                let mut suggestions = Suggestions::new(options, direction, &self.buffer);
+                if self.options.narrow_suggestions && self.options.display_suggestion_options {
+                    suggestions.narrow(suggestions.query(&self.buffer));
                 }
+                self.suggestions = Some(suggestions);
+            }
+        }
+    }
+
+    // Replaces the span of the options with their longest common prefix, if it extends what was
+    // typed
+    fn insert_common_prefix(&mut self, options: &[Suggestion<'_>]) -> bool {
+        let Some((span, prefix)) =
+            prefix_to_insert(options, &self.buffer, &self.options.constraints)
+        else {
+            return false;
+        };
+
+        if self.buffer.replace_range(span, &prefix).is_ok() {
+            self.update_completion();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_narrowing(&self) -> bool {
+        self.suggestions
+            .as_ref()
+            .is_some_and(|suggestions| suggestions.all.is_some())
+    }
+
+    fn narrow(&mut self) -> Result<(), Error> {
+        if let Some(suggestions) = &mut self.suggestions {
+            suggestions.narrow(suggestions.query(&self.buffer));
+        }
+        self.print_suggestions()
+    }
+
+    pub(super) fn select_column(&mut self, direction: Direction) -> Result<(), Error> {
+        if let Some(suggestions) = &mut self.suggestions {
+            let rows = if self.options.display_suggestion_options {
+                let terminal_width = usize::from(crossterm::terminal::size()?.0);
+                super::writer::Grid::new(
+                    &suggestions.options,
+                    self.options.suggestion_options_layout,
+                    terminal_width,
+                )
+                .rows()
+            } else {
+                suggestions.options.len()
+            };
+            suggestions.jump(direction, rows);
+            self.print_suggestions()
+        } else {
+            Ok(())
+        }
+    }
+
+    pub(super) fn menu_layout(&self) -> Option<Layout> {
+        self.suggestions.as_ref().map(|_| {
+            if self.options.display_suggestion_options {
+                self.options.suggestion_options_layout
             } else {
-                let options = suggester.suggest_for(self);
-                if !options.is_empty() {
-                    self.suggestions = Some(Suggestions::new(options, direction));
-                    let suggestions = self.suggestions.as_ref().unwrap();
-                    self.writer.print_selected_suggestion(
-                        suggestions.index.unwrap(),
+                Layout::List
+            }
+        })
+    }
+
+    fn print_suggestions(&mut self) -> Result<(), Error> {
+        match &self.suggestions {
+            Some(suggestions) if suggestions.all.is_some() => {
+                self.writer.print(&self.buffer, None)?;
+                if !suggestions.options.is_empty() {
+                    self.writer.print_suggestion_options(
+                        suggestions.index,
                         &suggestions.options,
+                        self.options.suggestion_options_layout,
+                        self.options.suggestion_options_height,
                     )?;
+                }
+                Ok(())
+            }
+            Some(suggestions) if suggestions.index.is_some() => {
+                let preview = suggestions.preview(&self.buffer, &self.options.constraints);
+                self.writer
+                    .print(preview.as_ref().unwrap_or(&self.buffer), None)?;
 
-                    if self.display_suggestion_options {
-                        self.writer.print_suggestion_options(
-                            suggestions.index.unwrap(),
-                            &suggestions.options,
-                        )?;
-                    }
-                    return Ok(());
+                if self.options.display_suggestion_options {
+                    self.writer.print_suggestion_options(
+                        suggestions.index,
+                        &suggestions.options,
+                        self.options.suggestion_options_layout,
+                        self.options.suggestion_options_height,
+                    )?;
                 }
+                Ok(())
             }
+            _ => self.print(),
         }
-
-        self.writer.print(&self.buffer, self.completion.as_deref())
     }
 
     pub(super) fn is_suggesting(&self) -> bool {
-        self.suggestions.is_some()
+        self.suggestions.is_some() || self.pending_suggestion.is_some()
     }
 
     pub(super) fn cancel_suggestion(&mut self) -> Result<(), Error> {
         self.suggestions = None;
-        self.writer.print(&self.buffer, self.completion.as_deref())
+        self.pending_suggestion = None;
+        self.print()
     }
 
     fn try_take_suggestion(&mut self) {
-        if let Some(suggestion) = self.suggestions.take().and_then(Suggestions::take) {
+        self.pending_suggestion = None;
+        if let Some(suggestion) = self
+            .suggestions
+            .take()
+            .and_then(|suggestions| suggestions.preview(&self.buffer, &self.options.constraints))
+        {
             self.buffer = suggestion;
         }
     }
 }
 
-impl<C, S> From<Context<'_, '_, C, S>> for Buffer
+impl<C, S> Editor for Context<'_, '_, C, S>
 where
     C: Completer + ?Sized,
     S: Suggester + ?Sized,
 {
-    fn from(ctx: Context<'_, '_, C, S>) -> Self {
-        ctx.buffer
+    fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    fn buffer_mut(&mut self) -> &mut Buffer {
+        if !self.is_narrowing() {
+            self.try_take_suggestion();
+        }
+        self.edited = true;
+        &mut self.buffer
+    }
+
+    fn is_suggesting(&self) -> bool {
+        self.suggestions.is_some() || self.pending_suggestion.is_some()
+    }
+
+    fn completion(&self) -> Option<&Completion<'_>> {
+        self.completion.as_ref()
+    }
+
+    fn suggest(&mut self, direction: Direction) {
+        self.load_suggestions(direction);
+        self.edited = true;
+    }
+
+    fn cancel_suggestion(&mut self) {
+        self.suggestions = None;
+        self.pending_suggestion = None;
+        self.edited = true;
+    }
+
+    fn history_position(&self) -> Option<usize> {
+        self.history_position
     }
 }
 
@@ -167,30 +464,148 @@ where
     }
 }
 
+// The longest common prefix of the options, as far as the constraints allow, and the span it
+// replaces, if all options replace the same span and the prefix extends what was typed in it,
+// ignoring case
+fn prefix_to_insert(
+    options: &[Suggestion<'_>],
+    buffer: &Buffer,
+    constraints: &Constraints,
+) -> Option<(std::ops::Range<usize>, String)> {
+    let first = options.first()?;
+    let span = first.span().unwrap_or(0..buffer.len());
+    if options
+        .iter()
+        .any(|option| option.span().unwrap_or(0..buffer.len()) != span)
+    {
+        return None;
+    }
+
+    let prefix = options
+        .iter()
+        .map(Suggestion::replacement)
+        .fold(first.replacement(), common_prefix);
+    let (prefix, _) = constraints.filter_replacement(prefix, buffer, span.clone());
+    let typed = buffer.get(span.clone()).unwrap_or_default();
+    if prefix.chars().count() > typed.chars().count()
+        && crate::completion::starts_with_ignore_case(&prefix, typed)
+    {
+        Some((span, prefix))
+    } else {
+        None
+    }
+}
+
+// The entry of a history of `len` lines recalled after stepping from `position`, staying on the
+// oldest one and going back to the typed line past the most recent one
+fn step_history(position: Option<usize>, direction: Direction, len: usize) -> Option<usize> {
+    match (direction, position) {
+        (Direction::Backward, None) => (len > 0).then_some(0),
+        (Direction::Backward, Some(index)) => Some((index + 1).min(len - 1)),
+        (Direction::Forward, None | Some(0)) => None,
+        (Direction::Forward, Some(index)) => Some(index - 1),
+    }
+}
+
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let end = a
+        .char_indices()
+        .zip(b.chars())
+        .find(|((_, x), y)| x != y)
+        .map_or_else(|| a.len().min(b.len()), |((index, _), _)| index);
+    &a[..end]
+}
+
+// The part of the completion that is not in the buffer yet, and the position where it is rendered
+fn ghost<'a>(buffer: &Buffer, completion: Option<&'a Completion<'_>>) -> Option<(usize, &'a str)> {
+    let completion = completion?;
+    let text = completion.text();
+
+    if let Some(span) = completion.span() {
+        let typed = buffer.get(span.clone())?.chars().count();
+        let start = text
+            .char_indices()
+            .nth(typed)
+            .map_or(text.len(), |(index, _)| index);
+        Some((span.end, &text[start..]))
+    } else {
+        Some((buffer.len(), text))
+    }
+}
+
 struct Suggestions<'a> {
     index: Option<usize>,
-    options: Vec<std::borrow::Cow<'a, str>>,
+    options: Vec<Suggestion<'a>>,
+    // The full list of options, if they are being narrowed down by the buffer
+    all: Option<Vec<Suggestion<'a>>>,
+    // The start of the span replaced by the options and the length of the line after it, so that
+    // the span follows the edits while narrowing
+    anchor: (usize, usize),
 }
 
 impl<'a> Suggestions<'a> {
-    fn new(options: Vec<std::borrow::Cow<'a, str>>, direction: Direction) -> Self {
+    fn new(options: Vec<Suggestion<'a>>, direction: Direction, buffer: &str) -> Self {
         let index = match direction {
             Direction::Forward => 0,
             Direction::Backward => options.len() - 1,
         };
 
+        let span = options
+            .first()
+            .and_then(Suggestion::span)
+            .unwrap_or(0..buffer.len());
+
         Self {
             options,
             index: Some(index),
+            all: None,
+            anchor: (span.start, buffer.len().saturating_sub(span.end)),
         }
     }
 
+    fn span(&self, buffer: &str) -> std::ops::Range<usize> {
+        let (start, tail) = self.anchor;
+        start..buffer.len().saturating_sub(tail).max(start)
+    }
+
+    fn query<'b>(&self, buffer: &'b str) -> &'b str {
+        buffer.get(self.span(buffer)).unwrap_or_default()
+    }
+
+    fn narrow(&mut self, query: &str) {
+        let all = self
+            .all
+            .get_or_insert_with(|| std::mem::take(&mut self.options));
+
+        self.options = if query.is_empty() {
+            all.clone()
+        } else {
+            let mut matches = all
+                .iter()
+                .filter_map(|option| {
+                    crate::completion::fuzzy_match(query, option.display())
+                        .map(|m| (m.score, option.clone().with_highlights(m.positions)))
+                })
+                .collect::<Vec<_>>();
+            matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+            matches.into_iter().map(|(_, option)| option).collect()
+        };
+
+        self.index = if self.options.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+    }
+
     // Allowed because it is more readable
     #[allow(clippy::match_same_arms)]
     fn cycle(&mut self, direction: Direction) {
         use Direction::{Backward, Forward};
 
-        let last_index = self.options.len() - 1;
+        let Some(last_index) = self.options.len().checked_sub(1) else {
+            return;
+        };
 
         self.index = match (direction, self.index) {
             (Forward, None) => Some(0),
@@ -202,8 +617,236 @@ impl<'a> Suggestions<'a> {
         };
     }
 
-    fn take(mut self) -> Option<Buffer> {
-        self.index
-            .map(|index| Buffer::from(self.options.swap_remove(index)))
+    // Allowed because it is more readable
+    #[allow(clippy::match_same_arms)]
+    fn jump(&mut self, direction: Direction, stride: usize) {
+        use Direction::{Backward, Forward};
+
+        let Some(last_index) = self.options.len().checked_sub(1) else {
+            return;
+        };
+
+        self.index = match (direction, self.index) {
+            (Forward, None) => Some(0),
+            (Forward, Some(index)) if index / stride == last_index / stride => Some(index),
+            (Forward, Some(index)) => Some((index + stride).min(last_index)),
+            (Backward, None) => Some(last_index),
+            (Backward, Some(index)) => Some(index.checked_sub(stride).unwrap_or(index)),
+        };
+    }
+
+    fn preview(&self, buffer: &Buffer, constraints: &Constraints) -> Option<Buffer> {
+        let option = self.options.get(self.index?)?;
+        let span = if self.all.is_some() {
+            self.span(buffer)
+        } else {
+            option.span().unwrap_or(0..buffer.len())
+        };
+
+        let (replacement, _) =
+            constraints.filter_replacement(option.replacement(), buffer, span.clone());
+        let mut preview = buffer.clone();
+        preview.replace_range(span, &replacement).ok()?;
+        Some(preview)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        common_prefix, ghost, prefix_to_insert, step_history, Buffer, Completion, Constraints,
+        Direction, Suggestion, Suggestions,
+    };
+
+    fn suggestions() -> Suggestions<'static> {
+        Suggestions::new(
+            ["cargo check", "git checkout", "git commit"]
+                .into_iter()
+                .map(Suggestion::from)
+                .collect(),
+            Direction::Forward,
+            "",
+        )
+    }
+
+    #[test]
+    fn narrow_keeps_matches_from_the_full_list() {
+        let mut suggestions = suggestions();
+
+        suggestions.narrow("gco");
+        assert_eq!(suggestions.options, vec!["git commit", "git checkout"]);
+        assert_eq!(suggestions.options[0].highlights(), &[0, 4, 5]);
+        assert_eq!(suggestions.index, Some(0));
+
+        suggestions.narrow("gcm");
+        assert_eq!(suggestions.options, vec!["git commit"]);
+
+        suggestions.narrow("c");
+        assert_eq!(suggestions.options.len(), 3);
+    }
+
+    #[test]
+    fn narrow_to_nothing_deselects() {
+        let mut suggestions = suggestions();
+
+        suggestions.narrow("xyz");
+        assert!(suggestions.options.is_empty());
+        assert_eq!(suggestions.index, None);
+
+        suggestions.cycle(Direction::Forward);
+        suggestions.jump(Direction::Backward, 2);
+        assert_eq!(suggestions.index, None);
+        assert!(suggestions
+            .preview(&Buffer::new(), &Constraints::new())
+            .is_none());
+    }
+
+    #[test]
+    fn narrow_with_empty_query_restores_the_order() {
+        let mut suggestions = suggestions();
+
+        suggestions.narrow("gcm");
+        suggestions.narrow("");
+        assert_eq!(
+            suggestions.options,
+            vec!["cargo check", "git checkout", "git commit"]
+        );
+        assert_eq!(suggestions.options[0].highlights(), &[] as &[usize]);
+    }
+
+    #[test]
+    fn preview_replaces_the_span_in_place() {
+        let buffer = Buffer::new_with_cursor("git chec --force", 8).unwrap();
+        let suggestions = Suggestions::new(
+            vec![
+                Suggestion::new("checkout").with_span(4..8),
+                Suggestion::new("cherry-pick").with_span(4..8),
+            ],
+            Direction::Forward,
+            &buffer,
+        );
+
+        let preview = suggestions.preview(&buffer, &Constraints::new()).unwrap();
+        assert_eq!(preview.as_str(), "git checkout --force");
+        assert_eq!(preview.cursor(), 12);
+    }
+
+    #[test]
+    fn narrow_follows_the_span_while_editing() {
+        let mut buffer = Buffer::new_with_cursor("git c --force", 5).unwrap();
+        let mut suggestions = Suggestions::new(
+            vec![
+                Suggestion::new("checkout").with_span(4..5),
+                Suggestion::new("commit").with_span(4..5),
+            ],
+            Direction::Forward,
+            &buffer,
+        );
+
+        buffer.write('m');
+        suggestions.narrow(suggestions.query(&buffer));
+        assert_eq!(suggestions.options, vec!["commit"]);
+
+        let preview = suggestions.preview(&buffer, &Constraints::new()).unwrap();
+        assert_eq!(preview.as_str(), "git commit --force");
+        assert_eq!(preview.cursor(), 10);
+    }
+
+    #[test]
+    fn ghost_skips_the_typed_part_of_the_span() {
+        let buffer = Buffer::from("gi --force");
+        let completion = Completion::replacing(0..2, "Git");
+        assert_eq!(ghost(&buffer, Some(&completion)), Some((2, "t")));
+
+        let completion = Completion::from("!");
+        assert_eq!(ghost(&buffer, Some(&completion)), Some((10, "!")));
+    }
+
+    // Allowed because it makes test clearer
+    #[allow(clippy::non_ascii_literal)]
+    #[test]
+    fn common_prefix_stops_at_the_first_difference() {
+        assert_eq!(common_prefix("git checkout", "git commit"), "git c");
+        assert_eq!(common_prefix("git", "git commit"), "git");
+        assert_eq!(common_prefix("git commit", "git"), "git");
+        assert_eq!(common_prefix("cargo", "git"), "");
+        assert_eq!(common_prefix("café", "cafè"), "caf");
+    }
+
+    #[test]
+    fn common_prefix_only_extends_what_was_typed() {
+        let options = vec![
+            Suggestion::new("git commit"),
+            Suggestion::new("git checkout"),
+        ];
+        assert_eq!(
+            prefix_to_insert(&options, &"gi".into(), &Constraints::new()),
+            Some((0..2, String::from("git c")))
+        );
+        assert_eq!(
+            prefix_to_insert(&options, &"gco".into(), &Constraints::new()),
+            None
+        );
+        assert_eq!(
+            prefix_to_insert(&options, &"git c".into(), &Constraints::new()),
+            None
+        );
+
+        let options = vec![Suggestion::new("Git"), Suggestion::new("Gitk")];
+        assert_eq!(
+            prefix_to_insert(&options, &"gi".into(), &Constraints::new()),
+            Some((0..2, String::from("Git")))
+        );
+    }
+
+    #[test]
+    fn common_prefix_is_constrained() {
+        let options = vec![
+            Suggestion::new("git commit"),
+            Suggestion::new("git checkout"),
+        ];
+        assert_eq!(
+            prefix_to_insert(&options, &"gi".into(), &Constraints::new().max_length(3)),
+            Some((0..2, String::from("git")))
+        );
+        assert_eq!(
+            prefix_to_insert(&options, &"GI".into(), &Constraints::new().uppercase(true)),
+            Some((0..2, String::from("GIT C")))
+        );
+        assert_eq!(
+            prefix_to_insert(&options, &"gi".into(), &Constraints::new().max_length(2)),
+            None
+        );
+    }
+
+    #[test]
+    fn preview_is_constrained() {
+        let buffer = Buffer::new_with_cursor("add 1", 5).unwrap();
+        let suggestions = Suggestions::new(
+            vec![Suggestion::new("12a34").with_span(4..5)],
+            Direction::Forward,
+            &buffer,
+        );
+
+        let constraints = Constraints::new()
+            .allowed(|c| c.is_ascii_digit() || c == ' ' || c.is_ascii_lowercase())
+            .max_length(7);
+        let preview = suggestions.preview(&buffer, &constraints).unwrap();
+        assert_eq!(preview.as_str(), "add 12a");
+
+        let constraints = Constraints::new().allowed(|c| !c.is_ascii_lowercase() || c == 'd');
+        let preview = suggestions.preview(&buffer, &constraints).unwrap();
+        assert_eq!(preview.as_str(), "add 1234");
+    }
+
+    #[test]
+    fn history_steps_back_to_the_oldest_and_forward_to_the_typed_line() {
+        assert_eq!(step_history(None, Direction::Backward, 0), None);
+        assert_eq!(step_history(None, Direction::Backward, 2), Some(0));
+        assert_eq!(step_history(Some(0), Direction::Backward, 2), Some(1));
+        assert_eq!(step_history(Some(1), Direction::Backward, 2), Some(1));
+        assert_eq!(step_history(Some(1), Direction::Forward, 2), Some(0));
+        assert_eq!(step_history(Some(0), Direction::Forward, 2), None);
+        assert_eq!(step_history(None, Direction::Forward, 2), None);
     }
 }
@@ -0,0 +1,155 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// The editor set by the environment, falling back to the usual default of the platform
+pub(super) fn editor() -> String {
+    ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|variable| std::env::var(variable).ok())
+        .find(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| String::from(if cfg!(windows) { "notepad" } else { "vi" }))
+}
+
+// Edits `text` in a temporary file with `editor`, which may include arguments
+//
+// Returns `None` if no editor is given or it exited with an error, e.g. `:cq` in vim
+pub(super) fn edit(editor: &str, text: &str) -> std::io::Result<Option<String>> {
+    let mut words = editor.split_whitespace();
+    let Some(program) = words.next() else {
+        return Ok(None);
+    };
+
+    let path = create_temp_file(text)?;
+
+    let edited = match std::process::Command::new(program)
+        .args(words)
+        .arg(&path)
+        .status()
+    {
+        Ok(status) if status.success() => std::fs::read_to_string(&path).map(Some),
+        Ok(_) => Ok(None),
+        Err(error) => Err(error),
+    };
+
+    // The file is only scratch space, so failing to remove it is not worth failing the edit
+    let _ = std::fs::remove_file(&path);
+
+    // The buffer is a single line
+    edited.map(|edited| edited.map(|edited| edited.lines().collect::<Vec<_>>().join(" ")))
+}
+
+// Writes `text` to a new file that only the current user can read, never reusing an existing path
+// since another user could have placed a symlink there
+fn create_temp_file(text: &str) -> std::io::Result<std::path::PathBuf> {
+    use std::io::Write;
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    const ATTEMPTS: usize = 100;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    for _ in 0..ATTEMPTS {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.subsec_nanos());
+        let path = std::env::temp_dir().join(format!(
+            "rucline-{}-{}-{nanos}.txt",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        match options.open(&path) {
+            Ok(mut file) => {
+                if let Err(error) = file.write_all(text.as_bytes()) {
+                    let _ = std::fs::remove_file(&path);
+                    return Err(error);
+                }
+                return Ok(path);
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    Err(std::io::Error::new(
+        std::io::ErrorKind::AlreadyExists,
+        "no unused temporary file name was found",
+    ))
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use super::{create_temp_file, edit};
+
+    struct Script(std::path::PathBuf);
+
+    impl Script {
+        fn new(name: &str, body: &str) -> Self {
+            let path = std::env::temp_dir()
+                .join(format!("rucline-editor-{}-{name}.sh", std::process::id()));
+            std::fs::write(&path, body).unwrap();
+            Self(path)
+        }
+
+        fn command(&self) -> String {
+            format!("sh {}", self.0.display())
+        }
+    }
+
+    impl Drop for Script {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn read_back_the_edited_file() {
+        let script = Script::new(
+            "append",
+            "printf '%s --force\\n' \"$(cat \"$1\")\" > \"$1\"\n",
+        );
+        assert_eq!(
+            edit(&script.command(), "git push").unwrap().as_deref(),
+            Some("git push --force")
+        );
+    }
+
+    #[test]
+    fn join_lines() {
+        let script = Script::new("lines", "printf 'git add .\\ngit commit\\n' > \"$1\"\n");
+        assert_eq!(
+            edit(&script.command(), "").unwrap().as_deref(),
+            Some("git add . git commit")
+        );
+    }
+
+    #[test]
+    fn keep_nothing_if_the_editor_fails() {
+        let script = Script::new("fail", "echo changed > \"$1\"\nexit 1\n");
+        assert_eq!(edit(&script.command(), "text").unwrap(), None);
+        assert_eq!(edit(" ", "text").unwrap(), None);
+    }
+
+    #[test]
+    fn fail_if_the_editor_cannot_be_run() {
+        assert!(edit("rucline-missing-editor", "text").is_err());
+    }
+
+    #[test]
+    fn create_a_private_file_at_a_new_path() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let first = create_temp_file("text").unwrap();
+        let second = create_temp_file("text").unwrap();
+        let mode = std::fs::metadata(&first).unwrap().permissions().mode();
+        let contents = std::fs::read_to_string(&first).unwrap();
+        let _ = std::fs::remove_file(&first);
+        let _ = std::fs::remove_file(&second);
+
+        assert_ne!(first, second);
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(contents, "text");
+    }
+}
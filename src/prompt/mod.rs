@@ -31,95 +31,167 @@
 //! [`Builder`]: trait.Builder.html
 
 mod builder;
+mod confirm;
+mod constraints;
 mod context;
+mod external;
+mod numeric;
+mod options;
+mod select;
 mod writer;
 
 use context::Context;
 use writer::Writer;
 
-use crate::actions::{action_for, Action, Direction, Overrider, Range, Scope};
-use crate::completion::{Completer, Suggester};
+use crate::actions::{
+    actions_for, Action, Direction, Editor, EditorOverrider, Handler, Range, Scope,
+};
+use crate::completion::{Completer, Completion, Suggester, Suggestion};
 use crate::Buffer;
 
 pub use builder::{Builder, Prompt};
+pub use confirm::Confirm;
+pub use constraints::Constraints;
+pub use numeric::{Number, Numeric};
+pub use options::Options;
+pub use select::{MultiSelect, Select};
 
-/// The outcome of [`read_line`], being either accepted or canceled by the user.
+const PENDING_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(20);
+
+/// How the list of suggestion options is rendered underneath the prompt.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Layout {
+    /// Each suggestion option is rendered in its own row. This is the default.
+    List,
+    /// Suggestion options are rendered in as many columns as fit the terminal width, filling
+    /// each column top to bottom, like shells do.
+    Grid,
+}
+
+/// What the first [`Suggest`] action does, usually associated with the `Tab` key.
+///
+/// [`Suggest`]: ../actions/enum.Action.html#variant.Suggest
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TabBehavior {
+    /// Selects the first suggestion right away, and later [`Suggest`] actions cycle through the
+    /// suggestions. This is the default.
+    ///
+    /// [`Suggest`]: ../actions/enum.Action.html#variant.Suggest
+    Cycle,
+    /// Inserts the longest common prefix of all suggestions, like shells do. If there is nothing
+    /// to insert, i.e. what was typed already is the longest common prefix or the prefix does not
+    /// start with what was typed, the suggestions are listed and cycled through as with
+    /// [`Cycle`].
+    ///
+    /// [`Cycle`]: enum.TabBehavior.html#variant.Cycle
+    CommonPrefix,
+}
+
+/// The outcome of [`read_line`], being either accepted, canceled or interrupted by the user, or
+/// the end of the input.
+///
+/// The accepted value is the text of the line, unless it is parsed with [`read_parsed`].
 ///
 /// [`read_line`]: fn.read_line.html
-pub enum Outcome {
+/// [`read_parsed`]: trait.Builder.html#method.read_parsed
+pub enum Outcome<T = String> {
     /// If the user accepts the prompt input, i.e. an [`Accept`] event was emitted. this variant will
-    /// contain the accepted text.
+    /// contain the accepted value.
     ///
     /// [`Accept`]: ../actions/enum.Action.html#variant.Accept
-    Accepted(String),
+    Accepted(T),
     /// If the user cancels the prompt input, i.e. a [`Cancel`] event was emitted. this variant will
     /// contain the rejected buffer, with text and cursor position intact from the moment of
     /// rejection.
     ///
     /// [`Cancel`]: ../actions/enum.Action.html#variant.Cancel
     Canceled(Buffer),
+    /// If the user signals the end of the input, i.e. an [`Eof`] event was emitted, which by
+    /// default happens with `Ctrl-D` on an empty line.
+    ///
+    /// [`Eof`]: ../actions/enum.Action.html#variant.Eof
+    Eof,
+    /// If the user interrupts the prompt input, i.e. an [`Interrupt`] event was emitted, which by
+    /// default happens with `Ctrl-C`. This variant will contain the discarded buffer, with text
+    /// and cursor position intact from the moment of the interruption.
+    ///
+    /// [`Interrupt`]: ../actions/enum.Action.html#variant.Interrupt
+    Interrupted(Buffer),
 }
 
-impl Outcome {
+impl<T> Outcome<T> {
     /// Returns true if the outcome was accepted.
     #[must_use]
     pub fn was_accepted(&self) -> bool {
         matches!(self, Outcome::Accepted(_))
     }
 
-    /// Returns the accepted text.
+    /// Returns the accepted value.
     ///
     /// # Panics
     ///
-    /// Panics if the [`Outcome`] is [`Canceled`]
+    /// Panics if the [`Outcome`] is not [`Accepted`]
     ///
     /// [`Outcome`]: enum.Outcome.html
-    /// [`Canceled`]: enum.Outcome.html#variant.Canceled
+    /// [`Accepted`]: enum.Outcome.html#variant.Accepted
     #[must_use]
-    pub fn unwrap(self) -> String {
-        if let Outcome::Accepted(string) = self {
-            string
-        } else {
-            panic!("called `Outcome::unwrap()` on a `Canceled` value")
+    pub fn unwrap(self) -> T {
+        match self {
+            Outcome::Accepted(value) => value,
+            Outcome::Canceled(_) => panic!("called `Outcome::unwrap()` on a `Canceled` value"),
+            Outcome::Eof => panic!("called `Outcome::unwrap()` on an `Eof` value"),
+            Outcome::Interrupted(_) => {
+                panic!("called `Outcome::unwrap()` on an `Interrupted` value")
+            }
         }
     }
 
-    /// Converts this [`Outcome`] into an [`Option`] of the accepted text.
+    /// Returns true if the outcome is the end of the input.
+    #[must_use]
+    pub fn is_eof(&self) -> bool {
+        matches!(self, Outcome::Eof)
+    }
+
+    /// Converts this [`Outcome`] into an [`Option`] of the accepted value.
     ///
     /// # Return
-    /// * `Some(String)` - If the [`Outcome`] is [`accepted`].
-    /// * `None` - If the [`Outcome`] is [`canceled`].
+    /// * `Some(T)` - If the [`Outcome`] is [`accepted`].
+    /// * `None` - Otherwise.
     ///
     /// [`Outcome`]: enum.Outcome.html
     /// [`Option`]: std::option::Option
     /// [`accepted`]: enum.Outcome.html#variant.Accepted
-    /// [`canceled`]: enum.Outcome.html#variant.Canceled
     #[must_use]
-    pub fn some(self) -> Option<String> {
+    pub fn some(self) -> Option<T> {
         match self {
-            Outcome::Accepted(string) => Some(string),
-            Outcome::Canceled(_) => None,
+            Outcome::Accepted(value) => Some(value),
+            Outcome::Canceled(_) | Outcome::Eof | Outcome::Interrupted(_) => None,
         }
     }
 
-    /// Converts this [`Outcome`] into a [`Result`] containing the accepted text or the canceled buffer.
+    /// Converts this [`Outcome`] into a [`Result`] containing the accepted value or the canceled
+    /// buffer.
     ///
     /// # Return
-    /// * `Ok(String)` - If the [`Outcome`] is [`accepted`].
-    /// * `Err(Buffer)` - If the [`Outcome`] is [`canceled`].
+    /// * `Ok(T)` - If the [`Outcome`] is [`accepted`].
+    /// * `Err(Buffer)` - If the [`Outcome`] is [`canceled`] or [`interrupted`], or an empty buffer
+    ///   if it is the [`end of the input`].
     ///
     /// # Errors
-    /// * [`Buffer`] - If the user canceled the input.
+    /// * [`Buffer`] - If the user canceled or interrupted the input, or ended it.
     ///
     /// [`Outcome`]: enum.Outcome.html
     /// [`Result`]: std::result::Result
     /// [`Buffer`]: ../buffer/struct.Buffer.html
     /// [`accepted`]: enum.Outcome.html#variant.Accepted
     /// [`canceled`]: enum.Outcome.html#variant.Canceled
-    pub fn ok(self) -> Result<String, Buffer> {
+    /// [`end of the input`]: enum.Outcome.html#variant.Eof
+    /// [`interrupted`]: enum.Outcome.html#variant.Interrupted
+    pub fn ok(self) -> Result<T, Buffer> {
         match self {
-            Outcome::Accepted(string) => Ok(string),
-            Outcome::Canceled(buffer) => Err(buffer),
+            Outcome::Accepted(value) => Ok(value),
+            Outcome::Canceled(buffer) | Outcome::Interrupted(buffer) => Err(buffer),
+            Outcome::Eof => Err(Buffer::new()),
         }
     }
 }
@@ -128,14 +200,17 @@ impl Outcome {
 /// Analogous to `std::io::stdin().read_line()`, however providing all the customization
 /// configured in the passed parameters.
 ///
+/// The settings of the prompt are passed as [`Options`], so that new settings do not change the
+/// parameters of this method.
+///
 /// This method will block until an input is committed by the user.
 ///
 /// Calling this method directly can be cumbersome, therefore it is recommended to use the helper
 /// [`Prompt`] or [`Builder`] to craft the call.
 ///
 /// # Return
-/// * [`Outcome`] - Either [`Accepted`] containing the user input, or [`Canceled`]
-/// containing the rejected [`buffer`].
+/// * [`Outcome`] - Either [`Accepted`] containing the user input, [`Canceled`] or [`Interrupted`]
+///   containing the rejected [`buffer`], or [`Eof`].
 ///
 /// # Errors
 /// * [`Error`] - If an error occurred while reading the user input.
@@ -143,52 +218,146 @@ impl Outcome {
 /// [`Accepted`]: enum.Outcome.html#variant.Accepted
 /// [`Builder`]: trait.Builder.html
 /// [`Canceled`]: enum.Outcome.html#variant.Canceled
+/// [`Eof`]: enum.Outcome.html#variant.Eof
 /// [`Error`]: ../enum.Error.html
+/// [`Interrupted`]: enum.Outcome.html#variant.Interrupted
+/// [`Options`]: struct.Options.html
 /// [`Outcome`]: enum.Outcome.html
 /// [`Prompt`]: struct.Prompt.html
 /// [`buffer`]: ../buffer/struct.Buffer.html
-pub fn read_line<O, C, S>(
+pub fn read_line<O, C, S, H>(
     prompt: Option<&str>,
     buffer: Option<Buffer>,
-    erase_after_read: bool,
-    display_suggestion_options: bool,
+    options: &Options,
     overrider: Option<&O>,
     completer: Option<&C>,
     suggester: Option<&S>,
+    handler: Option<&H>,
 ) -> Result<Outcome, crate::Error>
 where
-    O: Overrider + ?Sized,
+    O: EditorOverrider + ?Sized,
     C: Completer + ?Sized,
     S: Suggester + ?Sized,
+    H: Handler + ?Sized,
 {
-    let mut context = Context::new(
-        erase_after_read,
-        display_suggestion_options,
+    read(
         prompt,
         buffer,
+        options.clone(),
+        overrider,
         completer,
         suggester,
-    )?;
+        handler,
+        &|text| Ok(text.to_owned()),
+    )
+}
+
+// Reads a line as `read_line` does, only accepting it once `parse` succeeds
+//
+// Allowed because it mirrors the parameters of `read_line`
+#[allow(clippy::too_many_arguments)]
+fn read<T, O, C, S, H>(
+    prompt: Option<&str>,
+    buffer: Option<Buffer>,
+    options: Options,
+    overrider: Option<&O>,
+    completer: Option<&C>,
+    suggester: Option<&S>,
+    handler: Option<&H>,
+    parse: &dyn Fn(&str) -> Result<T, String>,
+) -> Result<Outcome<T>, crate::Error>
+where
+    O: EditorOverrider + ?Sized,
+    C: Completer + ?Sized,
+    S: Suggester + ?Sized,
+    H: Handler + ?Sized,
+{
+    let mut context = Context::new(options, prompt, buffer, completer, suggester)?;
 
     context.print()?;
     loop {
-        if let crossterm::event::Event::Key(e) = crossterm::event::read()? {
-            match action_for(overrider, e, &context) {
-                Action::Write(c) => context.write(c)?,
-                Action::Delete(scope) => context.delete(scope)?,
-                Action::Move(range, direction) => context.move_cursor(range, direction)?,
-                Action::Complete(range) => context.complete(range)?,
-                Action::Suggest(direction) => context.suggest(direction)?,
-                Action::NoOp => continue,
-                Action::Cancel => {
-                    if context.is_suggesting() {
-                        context.cancel_suggestion()?;
-                    } else {
-                        return Ok(Outcome::Canceled(context.into()));
+        // Keep rendering the results of pending completers, even if no key is pressed
+        if context.is_pending() && !crossterm::event::poll(PENDING_POLL_INTERVAL)? {
+            context.refresh()?;
+            continue;
+        }
+
+        match crossterm::event::read()? {
+            crossterm::event::Event::Key(e) => {
+                let menu = context.menu_layout();
+                let completing = context.is_completing_at_cursor();
+                let actions = actions_for(overrider, e, &mut context, menu, completing);
+                context.render_edits()?;
+                for action in actions {
+                    if let Some(outcome) = take_action(&mut context, action, handler, parse)? {
+                        return Ok(outcome);
                     }
                 }
-                Action::Accept => return Ok(Outcome::Accepted(context.buffer_as_string())),
             }
+            crossterm::event::Event::Paste(text) => context.paste(&text)?,
+            _ => {}
         }
     }
 }
+
+// Returns the outcome if the action ends the prompt
+fn take_action<T, C, S, H>(
+    context: &mut Context<'_, '_, C, S>,
+    mut action: Action,
+    handler: Option<&H>,
+    parse: &dyn Fn(&str) -> Result<T, String>,
+) -> Result<Option<Outcome<T>>, crate::Error>
+where
+    C: Completer + ?Sized,
+    S: Suggester + ?Sized,
+    H: Handler + ?Sized,
+{
+    while let Action::Custom(id) = action {
+        action = match handler {
+            Some(handler) => context.handle(handler, id)?,
+            None => Action::NoOp,
+        };
+    }
+
+    match action {
+        Action::Write(c) => context.write(c)?,
+        Action::Delete(scope) => context.delete(scope)?,
+        Action::Move(range, direction) => context.move_cursor(range, direction)?,
+        Action::Complete(range) => context.complete(range)?,
+        Action::Suggest(direction) | Action::SelectRow(direction) => {
+            context.suggest(direction)?;
+        }
+        Action::SelectColumn(direction) => context.select_column(direction)?,
+        Action::Recall(direction) => context.recall(direction)?,
+        Action::EditExternally => context.edit_externally()?,
+        Action::NoOp | Action::Custom(_) => {}
+        Action::Cancel => {
+            if context.is_suggesting() {
+                context.cancel_suggestion()?;
+            } else {
+                context.finish()?;
+                return Ok(Some(Outcome::Canceled(Buffer::clone(context))));
+            }
+        }
+        Action::Accept => match parse(&context.buffer_as_string()) {
+            Ok(value) => {
+                context.finish()?;
+                return Ok(Some(Outcome::Accepted(value)));
+            }
+            // The text is kept for fixing
+            Err(message) => context.reject(&message)?,
+        },
+        Action::Eof => {
+            context.finish()?;
+            return Ok(Some(Outcome::Eof));
+        }
+        Action::Interrupt => {
+            // Discard the suggestion without rendering, so that the line is only printed once
+            Editor::cancel_suggestion(context);
+            context.finish()?;
+            return Ok(Some(Outcome::Interrupted(Buffer::clone(context))));
+        }
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,347 @@
+use super::builder::{ChainedLineReader, Dummy};
+use super::{Action, Buffer, Builder, Constraints, Editor, EditorOverrider, Outcome, Prompt};
+
+use crate::actions::{default_action, Event, KeyCode, Scope};
+use crate::Error;
+
+/// A primitive number type that can be read with [`Numeric`].
+///
+/// This trait is sealed and implemented for all integer and floating point types.
+///
+/// [`Numeric`]: struct.Numeric.html
+pub trait Number:
+    Copy + PartialOrd + std::str::FromStr + std::fmt::Display + private::Sealed
+{
+    /// The value stepped from when the input is empty.
+    const ZERO: Self;
+    /// The default increment.
+    const ONE: Self;
+    /// Whether the type has a decimal point.
+    const FLOAT: bool;
+    /// Whether the type has negative values.
+    const SIGNED: bool;
+
+    /// Adds `step`, or returns `None` on overflow.
+    fn increment(self, step: Self) -> Option<Self>;
+
+    /// Subtracts `step`, or returns `None` on overflow.
+    fn decrement(self, step: Self) -> Option<Self>;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_integer {
+    ($($t:ty, $signed:expr);*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl Number for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const FLOAT: bool = false;
+                const SIGNED: bool = $signed;
+
+                fn increment(self, step: Self) -> Option<Self> {
+                    self.checked_add(step)
+                }
+
+                fn decrement(self, step: Self) -> Option<Self> {
+                    self.checked_sub(step)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl Number for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                const FLOAT: bool = true;
+                const SIGNED: bool = true;
+
+                fn increment(self, step: Self) -> Option<Self> {
+                    Some(self + step)
+                }
+
+                fn decrement(self, step: Self) -> Option<Self> {
+                    Some(self - step)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    i8, true; i16, true; i32, true; i64, true; i128, true; isize, true;
+    u8, false; u16, false; u32, false; u64, false; u128, false; usize, false
+);
+impl_float!(f32, f64);
+
+/// A prompt for a number within optional bounds.
+///
+/// Only digits, a leading sign and, for floating point types, a decimal point can be typed.
+/// `Up` and `Down` step the value by an increment, staying within the bounds. Accepting a value
+/// that does not parse or is out of bounds keeps the prompt open, rendering the reason underneath
+/// it, as [`read_parsed`] does.
+///
+/// # Example
+///
+/// ```no_run
+/// use rucline::Outcome::Accepted;
+/// use rucline::prompt::Numeric;
+///
+/// if let Ok(Accepted(port)) = Numeric::<u16>::from("Port: ").min(1024).initial(8080).read() {
+///     println!("Listening on {port}");
+/// }
+/// ```
+///
+/// [`read_parsed`]: trait.Builder.html#method.read_parsed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Numeric<T: Number> {
+    prompt: Prompt,
+    bounds: Bounds<T>,
+}
+
+impl<T: Number> Numeric<T> {
+    /// Creates a new [`Numeric`] with no prompt text, no bounds and an increment of one.
+    ///
+    /// [`Numeric`]: struct.Numeric.html
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_prompt(Prompt::new())
+    }
+
+    fn with_prompt(prompt: Prompt) -> Self {
+        Self {
+            // Pasted text skips the overrider, so at least the characters are checked
+            prompt: prompt.constraints(Constraints::new().allowed(is_number_char::<T>)),
+            bounds: Bounds {
+                min: None,
+                max: None,
+                step: T::ONE,
+            },
+        }
+    }
+
+    /// Sets the lowest value that is accepted.
+    #[must_use]
+    pub fn min(mut self, min: T) -> Self {
+        self.bounds.min = Some(min);
+        self
+    }
+
+    /// Sets the highest value that is accepted.
+    #[must_use]
+    pub fn max(mut self, max: T) -> Self {
+        self.bounds.max = Some(max);
+        self
+    }
+
+    /// Sets the increment of `Up` and `Down`.
+    #[must_use]
+    pub fn step(mut self, step: T) -> Self {
+        self.bounds.step = step;
+        self
+    }
+
+    /// Prepopulates the prompt input with `value`.
+    #[must_use]
+    pub fn initial(mut self, value: T) -> Self {
+        self.prompt = self.prompt.buffer(value.to_string().into());
+        self
+    }
+
+    /// Controls if the prompt should be erased after the number is read.
+    ///
+    /// Defaults to `false`.
+    #[must_use]
+    pub fn erase_after_read(mut self, erase_after_read: bool) -> Self {
+        self.prompt = self.prompt.erase_after_read(erase_after_read);
+        self
+    }
+
+    /// Consumes this [`Numeric`] to read a number, blocking until it is accepted.
+    ///
+    /// # Errors
+    /// * [`Error`] - If an error occurred while reading the user input.
+    ///
+    /// [`Error`]: ../enum.Error.html
+    /// [`Numeric`]: struct.Numeric.html
+    pub fn read(self) -> Result<Outcome<T>, Error>
+    where
+        T::Err: std::fmt::Display,
+    {
+        let bounds = self.bounds;
+        self.prompt
+            .overrider(bounds)
+            .chain_read_line::<T, Dummy, Dummy, Dummy, Dummy>(None, None, None, None, &|text| {
+                bounds.parse(text)
+            })
+    }
+}
+
+impl<T: Number> Default for Numeric<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: ToString, T: Number> std::convert::From<S> for Numeric<T> {
+    fn from(s: S) -> Self {
+        Self::with_prompt(Prompt::from(s))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Bounds<T> {
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+}
+
+impl<T: Number> Bounds<T> {
+    fn parse(self, text: &str) -> Result<T, String>
+    where
+        T::Err: std::fmt::Display,
+    {
+        let value = text.parse::<T>().map_err(|error| error.to_string())?;
+        match (self.min, self.max) {
+            (Some(min), _) if value < min => Err(format!("must be at least {min}")),
+            (_, Some(max)) if value > max => Err(format!("must be at most {max}")),
+            _ => Ok(value),
+        }
+    }
+
+    fn clamp(self, value: T) -> T {
+        match (self.min, self.max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        }
+    }
+
+    // The value after stepping up or down from `text`, starting from zero if it does not parse
+    fn stepped(self, text: &str, up: bool) -> T {
+        let Ok(value) = text.parse::<T>() else {
+            return self.clamp(T::ZERO);
+        };
+
+        let stepped = if up {
+            value.increment(self.step).or(self.max)
+        } else {
+            value.decrement(self.step).or(self.min)
+        };
+        self.clamp(stepped.unwrap_or(value))
+    }
+}
+
+impl<T: Number> EditorOverrider for Bounds<T> {
+    fn override_with(&self, event: Event, editor: &mut dyn Editor) -> Option<Vec<Action>> {
+        let up = match event.code {
+            KeyCode::Up => true,
+            KeyCode::Down => false,
+            _ => {
+                let Action::Write(c) = default_action(event, editor.buffer()) else {
+                    return None;
+                };
+
+                let mut typed = editor.buffer().clone();
+                typed.write(c);
+                return if is_partial_number(&typed, T::FLOAT, T::SIGNED) {
+                    None
+                } else {
+                    Some(vec![Action::NoOp])
+                };
+            }
+        };
+
+        let value = self.stepped(editor.buffer(), up).to_string();
+        Some(
+            std::iter::once(Action::Delete(Scope::WholeLine))
+                .chain(value.chars().map(Action::Write))
+                .collect(),
+        )
+    }
+}
+
+fn is_number_char<T: Number>(c: char) -> bool {
+    c.is_ascii_digit() || c == '+' || (c == '-' && T::SIGNED) || (c == '.' && T::FLOAT)
+}
+
+// Whether `text` can be completed into a number, e.g. `-` or `1.`
+fn is_partial_number(text: &Buffer, float: bool, signed: bool) -> bool {
+    let digits = match text.strip_prefix('-') {
+        Some(digits) if signed => digits,
+        Some(_) => return false,
+        None => text.strip_prefix('+').unwrap_or(text),
+    };
+
+    let mut decimal_points = 0;
+    digits.chars().all(|c| {
+        if c == '.' {
+            decimal_points += 1;
+            float && decimal_points == 1
+        } else {
+            c.is_ascii_digit()
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_partial_number, Bounds, Buffer, Number};
+
+    fn bounds<T: Number>(min: Option<T>, max: Option<T>, step: T) -> Bounds<T> {
+        Bounds { min, max, step }
+    }
+
+    #[test]
+    fn only_number_characters_are_typed() {
+        let partial =
+            |text: &str, float, signed| is_partial_number(&Buffer::from(text), float, signed);
+        assert!(partial("", false, false));
+        assert!(partial("+12", false, false));
+        assert!(partial("-", false, true));
+        assert!(!partial("-", false, false));
+        assert!(!partial("1-", false, true));
+        assert!(partial("-1.5", true, true));
+        assert!(!partial("1.5", false, true));
+        assert!(!partial("1.5.", true, true));
+        assert!(!partial("1e5", true, true));
+    }
+
+    #[test]
+    fn parse_within_bounds() {
+        let bounds = bounds(Some(1_u16), Some(10), 1);
+        assert_eq!(bounds.parse("5"), Ok(5));
+        assert_eq!(bounds.parse("0"), Err(String::from("must be at least 1")));
+        assert_eq!(bounds.parse("11"), Err(String::from("must be at most 10")));
+        assert!(bounds.parse("").is_err());
+    }
+
+    #[test]
+    fn step_within_bounds() {
+        let bounds = bounds(Some(1_u8), Some(10), 4);
+        assert_eq!(bounds.stepped("5", true), 9);
+        assert_eq!(bounds.stepped("9", true), 10);
+        assert_eq!(bounds.stepped("3", false), 1);
+        assert_eq!(bounds.stepped("", true), 1);
+        assert_eq!(bounds.stepped("50", false), 10);
+    }
+
+    // Allowed because the steps are exact in binary
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn step_without_overflowing() {
+        assert_eq!(bounds(None, None, 1_u8).stepped("0", false), 0);
+        assert_eq!(bounds(None, None, 1_u8).stepped("255", true), 255);
+        assert_eq!(bounds(None, None, 0.5_f64).stepped("-1", true), -0.5);
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Constraints, Layout, TabBehavior};
+
+/// The settings of a call to [`read_line`].
+///
+/// New settings may be added in later versions, so an [`Options`] can only be created with
+/// [`Options::new`] or `Options::default()` and then changed field by field. The [`Builder`]
+/// methods of the same names are usually more convenient.
+///
+/// # Example
+///
+/// ```no_run
+/// use rucline::actions::{Action, Editor, KeyBindings};
+/// use rucline::prompt::{read_line, Options};
+///
+/// let mut options = Options::new();
+/// options.suggestion_options_height = Some(3);
+///
+/// let outcome = read_line::<KeyBindings, Vec<&str>, _, fn(u32, &mut dyn Editor) -> Action>(
+///     Some("Color: "),
+///     None,
+///     &options,
+///     None,
+///     None,
+///     Some(&vec!["red", "green", "blue", "yellow", "purple"]),
+///     None,
+/// );
+/// ```
+///
+/// [`Builder`]: trait.Builder.html
+/// [`Options`]: struct.Options.html
+/// [`Options::new`]: struct.Options.html#method.new
+/// [`read_line`]: fn.read_line.html
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Options {
+    /// Whether the prompt is erased after the line is read. Defaults to `false`.
+    pub erase_after_read: bool,
+    /// Whether the list of suggestion options is rendered underneath the prompt. Defaults to
+    /// `true`.
+    pub display_suggestion_options: bool,
+    /// How the list of suggestion options is rendered. Defaults to [`Layout::List`].
+    ///
+    /// [`Layout::List`]: enum.Layout.html#variant.List
+    pub suggestion_options_layout: Layout,
+    /// The most rows the list of suggestion options takes, if any. Defaults to `None`.
+    pub suggestion_options_height: Option<usize>,
+    /// Whether typing while the list of suggestion options is open narrows it down. Defaults to
+    /// `false`.
+    pub narrow_suggestions: bool,
+    /// What the first [`Suggest`] action does. Defaults to [`TabBehavior::Cycle`].
+    ///
+    /// [`Suggest`]: ../actions/enum.Action.html#variant.Suggest
+    /// [`TabBehavior::Cycle`]: enum.TabBehavior.html#variant.Cycle
+    pub tab_behavior: TabBehavior,
+    /// Restrictions on what can go into the buffer. Defaults to allowing anything.
+    pub constraints: Constraints,
+    /// The text shown while the buffer is empty, if any. Defaults to `None`.
+    pub placeholder: Option<String>,
+    /// The lines previously read, most recent first, that [`Recall`] steps through. Defaults to
+    /// none.
+    ///
+    /// [`Recall`]: ../actions/enum.Action.html#variant.Recall
+    pub history: Vec<String>,
+}
+
+impl Options {
+    /// Creates a new [`Options`] with the default settings.
+    ///
+    /// [`Options`]: struct.Options.html
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            erase_after_read: false,
+            display_suggestion_options: true,
+            suggestion_options_layout: Layout::List,
+            suggestion_options_height: None,
+            narrow_suggestions: false,
+            tab_behavior: TabBehavior::Cycle,
+            constraints: Constraints::new(),
+            placeholder: None,
+            history: Vec::new(),
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::new()
+    }
+}
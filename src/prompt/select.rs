@@ -0,0 +1,484 @@
+use super::{Action, Buffer, Direction, Layout, Outcome, Suggestion, Writer};
+
+use crate::actions::{default_action, menu_action};
+use crate::Error;
+
+const CHECKED: &str = "[x] ";
+const UNCHECKED: &str = "[ ] ";
+const NO_MATCH: &str = "No option matches";
+
+/// A menu to pick one of a list of options.
+///
+/// The options are listed underneath the prompt. `Up` and `Down`, or `Tab` and `Shift-Tab`, move
+/// the selection, and typing narrows the list down to the options that fuzzy match the typed
+/// text. `Enter` picks the selected option, or shows an error if no option matches.
+///
+/// As with [`read_line`], `Esc` cancels, `Ctrl-C` interrupts and `Ctrl-D` on an empty filter
+/// ends the input.
+///
+/// # Example
+///
+/// ```no_run
+/// use rucline::Outcome::Accepted;
+/// use rucline::prompt::Select;
+///
+/// let colors = ["red", "green", "blue"];
+///
+/// if let Ok(Accepted(index)) = Select::new(colors).prompt("Color: ").read() {
+///     println!("Painting it {}", colors[index]);
+/// }
+/// ```
+///
+/// [`read_line`]: fn.read_line.html
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Select {
+    prompt: Option<String>,
+    options: Vec<String>,
+    height: Option<usize>,
+    erase_after_read: bool,
+}
+
+impl Select {
+    /// Creates a new [`Select`] from any collection of `options`.
+    ///
+    /// [`Select`]: struct.Select.html
+    #[must_use]
+    pub fn new<I>(options: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        Self {
+            prompt: None,
+            options: options
+                .into_iter()
+                .map(|option| option.to_string())
+                .collect(),
+            height: None,
+            erase_after_read: false,
+        }
+    }
+
+    /// Sets the text shown before the filter.
+    #[must_use]
+    pub fn prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    /// Limits the list to `height` rows, scrolling to keep the selection visible.
+    ///
+    /// Defaults to listing all options.
+    #[must_use]
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Controls if the prompt should be erased after an option is picked.
+    ///
+    /// Defaults to `false`.
+    #[must_use]
+    pub fn erase_after_read(mut self, erase_after_read: bool) -> Self {
+        self.erase_after_read = erase_after_read;
+        self
+    }
+
+    /// Consumes this [`Select`] to show the menu, blocking until an option is picked.
+    ///
+    /// # Return
+    /// * [`Outcome`] - Either [`Accepted`] containing the index of the picked option, [`Canceled`]
+    ///   or [`Interrupted`] containing the filter [`buffer`], or [`Eof`].
+    ///
+    /// # Errors
+    /// * [`Error`] - If an error occurred while reading the user input.
+    ///
+    /// [`Accepted`]: enum.Outcome.html#variant.Accepted
+    /// [`Canceled`]: enum.Outcome.html#variant.Canceled
+    /// [`Eof`]: enum.Outcome.html#variant.Eof
+    /// [`Error`]: ../enum.Error.html
+    /// [`Interrupted`]: enum.Outcome.html#variant.Interrupted
+    /// [`Outcome`]: enum.Outcome.html
+    /// [`Select`]: struct.Select.html
+    /// [`buffer`]: ../buffer/struct.Buffer.html
+    pub fn read(self) -> Result<Outcome<usize>, Error> {
+        let menu = Menu::new(self.options, false);
+        run(
+            menu,
+            self.prompt.as_deref(),
+            self.height,
+            self.erase_after_read,
+            |menu| menu.current().map(|index| (index, vec![index])),
+        )
+    }
+}
+
+/// A menu to pick any number of a list of options.
+///
+/// Works as [`Select`], except that `Space` checks or unchecks the selected option, and `Enter`
+/// picks all checked options.
+///
+/// # Example
+///
+/// ```no_run
+/// use rucline::Outcome::Accepted;
+/// use rucline::prompt::MultiSelect;
+///
+/// let toppings = ["cheese", "olives", "mushrooms"];
+///
+/// if let Ok(Accepted(indices)) = MultiSelect::new(toppings).prompt("Toppings: ").read() {
+///     println!("{} toppings", indices.len());
+/// }
+/// ```
+///
+/// [`Select`]: struct.Select.html
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MultiSelect {
+    select: Select,
+}
+
+impl MultiSelect {
+    /// Creates a new [`MultiSelect`] from any collection of `options`, all unchecked.
+    ///
+    /// [`MultiSelect`]: struct.MultiSelect.html
+    #[must_use]
+    pub fn new<I>(options: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        Self {
+            select: Select::new(options),
+        }
+    }
+
+    /// Sets the text shown before the filter.
+    #[must_use]
+    pub fn prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.select = self.select.prompt(prompt);
+        self
+    }
+
+    /// Limits the list to `height` rows, scrolling to keep the selection visible.
+    ///
+    /// Defaults to listing all options.
+    #[must_use]
+    pub fn height(mut self, height: usize) -> Self {
+        self.select = self.select.height(height);
+        self
+    }
+
+    /// Controls if the prompt should be erased after the options are picked.
+    ///
+    /// Defaults to `false`.
+    #[must_use]
+    pub fn erase_after_read(mut self, erase_after_read: bool) -> Self {
+        self.select = self.select.erase_after_read(erase_after_read);
+        self
+    }
+
+    /// Consumes this [`MultiSelect`] to show the menu, blocking until the options are picked.
+    ///
+    /// # Return
+    /// * [`Outcome`] - Either [`Accepted`] containing the indices of the checked options, in
+    ///   order, [`Canceled`] or [`Interrupted`] containing the filter [`buffer`], or [`Eof`].
+    ///
+    /// # Errors
+    /// * [`Error`] - If an error occurred while reading the user input.
+    ///
+    /// [`Accepted`]: enum.Outcome.html#variant.Accepted
+    /// [`Canceled`]: enum.Outcome.html#variant.Canceled
+    /// [`Eof`]: enum.Outcome.html#variant.Eof
+    /// [`Error`]: ../enum.Error.html
+    /// [`Interrupted`]: enum.Outcome.html#variant.Interrupted
+    /// [`MultiSelect`]: struct.MultiSelect.html
+    /// [`Outcome`]: enum.Outcome.html
+    /// [`buffer`]: ../buffer/struct.Buffer.html
+    pub fn read(self) -> Result<Outcome<Vec<usize>>, Error> {
+        let select = self.select;
+        let menu = Menu::new(select.options, true);
+        run(
+            menu,
+            select.prompt.as_deref(),
+            select.height,
+            select.erase_after_read,
+            |menu| {
+                let checked = menu.checked();
+                Some((checked.clone(), checked))
+            },
+        )
+    }
+}
+
+// Shows the menu until `pick` returns the value and the indices of the picked options
+fn run<T, F>(
+    mut menu: Menu,
+    prompt: Option<&str>,
+    height: Option<usize>,
+    erase_after_read: bool,
+    pick: F,
+) -> Result<Outcome<T>, Error>
+where
+    F: Fn(&Menu) -> Option<(T, Vec<usize>)>,
+{
+    let mut writer = Writer::new(erase_after_read, prompt, None)?;
+    print(&mut writer, &menu, height)?;
+
+    loop {
+        let crossterm::event::Event::Key(e) = crossterm::event::read()? else {
+            continue;
+        };
+
+        let action =
+            menu_action(e, Layout::List).unwrap_or_else(|| default_action(e, &menu.filter));
+        match menu.take_action(action) {
+            None => print(&mut writer, &menu, height)?,
+            Some(Outcome::Accepted(())) => {
+                if let Some((value, indices)) = pick(&menu) {
+                    let picked = indices
+                        .into_iter()
+                        .map(|index| menu.options[index].as_str())
+                        .collect::<Vec<_>>();
+                    writer.print(&Buffer::from(picked.join(", ").as_str()), None)?;
+                    return Ok(Outcome::Accepted(value));
+                }
+
+                // Rendered until the next print, as when a line cannot be parsed
+                print(&mut writer, &menu, height)?;
+                super::writer::print_error(NO_MATCH)?;
+            }
+            Some(Outcome::Canceled(buffer)) => return Ok(Outcome::Canceled(buffer)),
+            Some(Outcome::Eof) => return Ok(Outcome::Eof),
+            Some(Outcome::Interrupted(buffer)) => return Ok(Outcome::Interrupted(buffer)),
+        }
+    }
+}
+
+fn print(writer: &mut Writer, menu: &Menu, height: Option<usize>) -> Result<(), Error> {
+    writer.print(&menu.filter, None)?;
+    let options = menu.suggestions();
+    if options.is_empty() {
+        Ok(())
+    } else {
+        writer.print_suggestion_options(menu.selected, &options, Layout::List, height)
+    }
+}
+
+struct Menu {
+    options: Vec<String>,
+    filter: Buffer,
+    // The options matching the filter, best first, with the positions of the matched characters
+    matches: Vec<(usize, Vec<usize>)>,
+    // The index in `matches` of the selected option
+    selected: Option<usize>,
+    // Whether each option is checked, if several can be picked
+    checked: Option<Vec<bool>>,
+}
+
+impl Menu {
+    fn new(options: Vec<String>, multiple: bool) -> Self {
+        let mut menu = Self {
+            checked: multiple.then(|| vec![false; options.len()]),
+            options,
+            filter: Buffer::new(),
+            matches: Vec::new(),
+            selected: None,
+        };
+        menu.narrow();
+        menu
+    }
+
+    // Returns the outcome if the action ends the menu, with `Accepted` only requesting to pick
+    fn take_action(&mut self, action: Action) -> Option<Outcome<()>> {
+        match action {
+            Action::Write(' ') if self.checked.is_some() => self.toggle(),
+            Action::Write(c) => {
+                self.filter.write(c);
+                self.narrow();
+            }
+            Action::Delete(scope) => {
+                self.filter.delete(scope);
+                self.narrow();
+            }
+            Action::Move(range, direction) => self.filter.move_cursor(range, direction),
+            Action::Suggest(direction) | Action::SelectRow(direction) => self.select(direction),
+            Action::Accept => return Some(Outcome::Accepted(())),
+            Action::Cancel => return Some(Outcome::Canceled(self.filter.clone())),
+            Action::Eof => return Some(Outcome::Eof),
+            Action::Interrupt => return Some(Outcome::Interrupted(self.filter.clone())),
+            _ => {}
+        }
+        None
+    }
+
+    fn narrow(&mut self) {
+        self.matches = if self.filter.is_empty() {
+            (0..self.options.len())
+                .map(|index| (index, Vec::new()))
+                .collect()
+        } else {
+            let mut matches = self
+                .options
+                .iter()
+                .enumerate()
+                .filter_map(|(index, option)| {
+                    crate::completion::fuzzy_match(&self.filter, option)
+                        .map(|m| (m.score, (index, m.positions)))
+                })
+                .collect::<Vec<_>>();
+            matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+            matches.into_iter().map(|(_, m)| m).collect()
+        };
+
+        self.selected = if self.matches.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+    }
+
+    fn select(&mut self, direction: Direction) {
+        let Some(selected) = self.selected else {
+            return;
+        };
+
+        let len = self.matches.len();
+        self.selected = Some(match direction {
+            Direction::Forward => (selected + 1) % len,
+            Direction::Backward => (selected + len - 1) % len,
+        });
+    }
+
+    fn toggle(&mut self) {
+        if let (Some(index), Some(checked)) = (self.current(), &mut self.checked) {
+            checked[index] = !checked[index];
+        }
+    }
+
+    // The index of the selected option
+    fn current(&self) -> Option<usize> {
+        self.selected.map(|selected| self.matches[selected].0)
+    }
+
+    fn checked(&self) -> Vec<usize> {
+        self.checked
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(_, &checked)| checked)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn suggestions(&self) -> Vec<Suggestion<'_>> {
+        self.matches
+            .iter()
+            .map(|(index, positions)| {
+                let option = self.options[*index].as_str();
+                match &self.checked {
+                    Some(checked) => {
+                        let marker = if checked[*index] { CHECKED } else { UNCHECKED };
+                        Suggestion::new(option)
+                            .with_display(format!("{marker}{option}"))
+                            .with_highlights(positions.iter().map(|p| p + marker.len()).collect())
+                    }
+                    None => Suggestion::new(option).with_highlights(positions.clone()),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Action, Direction, Menu, Outcome};
+
+    fn menu(multiple: bool) -> Menu {
+        Menu::new(
+            ["git commit", "git checkout", "cargo check"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            multiple,
+        )
+    }
+
+    fn type_text(menu: &mut Menu, text: &str) {
+        for c in text.chars() {
+            menu.take_action(Action::Write(c));
+        }
+    }
+
+    #[test]
+    fn select_wraps_around() {
+        let mut menu = menu(false);
+        assert_eq!(menu.current(), Some(0));
+
+        menu.take_action(Action::SelectRow(Direction::Backward));
+        assert_eq!(menu.current(), Some(2));
+
+        menu.take_action(Action::Suggest(Direction::Forward));
+        assert_eq!(menu.current(), Some(0));
+    }
+
+    #[test]
+    fn typing_narrows_the_options() {
+        let mut menu = menu(false);
+
+        type_text(&mut menu, "chk");
+        assert_eq!(menu.current(), Some(1));
+        assert_eq!(menu.matches.len(), 2);
+
+        type_text(&mut menu, "x");
+        assert_eq!(menu.current(), None);
+        menu.take_action(Action::SelectRow(Direction::Forward));
+        assert_eq!(menu.current(), None);
+
+        menu.take_action(Action::Delete(crate::actions::Scope::WholeLine));
+        assert_eq!(menu.matches.len(), 3);
+    }
+
+    #[test]
+    fn space_checks_only_if_several_can_be_picked() {
+        let mut menu = menu(true);
+        menu.take_action(Action::Write(' '));
+        menu.take_action(Action::SelectRow(Direction::Backward));
+        menu.take_action(Action::Write(' '));
+        menu.take_action(Action::SelectRow(Direction::Forward));
+        menu.take_action(Action::Write(' '));
+        menu.take_action(Action::SelectRow(Direction::Backward));
+        assert_eq!(menu.checked(), vec![2]);
+
+        let suggestions = menu.suggestions();
+        assert_eq!(suggestions[0].display(), "[ ] git commit");
+        assert_eq!(suggestions[2].display(), "[x] cargo check");
+        assert_eq!(suggestions[2].replacement(), "cargo check");
+
+        let mut menu = self::menu(false);
+        menu.take_action(Action::Write(' '));
+        assert_eq!(menu.filter.as_str(), " ");
+        assert!(menu.checked().is_empty());
+    }
+
+    #[test]
+    fn highlights_follow_the_marker() {
+        let mut menu = menu(true);
+        type_text(&mut menu, "cargo");
+        assert_eq!(menu.suggestions()[0].highlights(), &[4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn end_with_an_outcome() {
+        let mut menu = menu(false);
+        assert!(menu.take_action(Action::NoOp).is_none());
+        assert!(matches!(
+            menu.take_action(Action::Accept),
+            Some(Outcome::Accepted(()))
+        ));
+        type_text(&mut menu, "git");
+        assert!(matches!(
+            menu.take_action(Action::Cancel),
+            Some(Outcome::Canceled(buffer)) if buffer.as_str() == "git"
+        ));
+    }
+}
@@ -1,18 +1,26 @@
-use super::Buffer;
+use super::{Buffer, Layout, Suggestion};
 
 use crate::Error;
 
 // TODO: Keep track of lines
 // TODO: Deal with colors
 pub(super) struct Writer {
+    prompt: Option<String>,
+    placeholder: Option<String>,
     erase_on_drop: Option<usize>,
     printed_length: usize,
     cursor_offset: usize,
+    options_offset: usize,
 }
 
 impl Writer {
-    pub(super) fn new(erase_on_drop: bool, prompt: Option<&str>) -> Result<Self, Error> {
+    pub(super) fn new(
+        erase_on_drop: bool,
+        prompt: Option<&str>,
+        placeholder: Option<&str>,
+    ) -> Result<Self, Error> {
         crossterm::terminal::enable_raw_mode()?;
+        enable_bracketed_paste();
         if let Some(prompt) = prompt {
             crossterm::queue!(std::io::stdout(), crossterm::style::Print(prompt))?;
         }
@@ -26,78 +34,105 @@ impl Writer {
         };
 
         Ok(Self {
+            prompt: prompt.map(ToOwned::to_owned),
+            placeholder: placeholder.map(ToOwned::to_owned),
             erase_on_drop,
             printed_length: 0,
             cursor_offset: 0,
+            options_offset: 0,
         })
     }
 
-    pub(super) fn print(&mut self, buffer: &Buffer, completion: Option<&str>) -> Result<(), Error> {
+    pub(super) fn print(
+        &mut self,
+        buffer: &Buffer,
+        completion: Option<(usize, &str)>,
+    ) -> Result<(), Error> {
+        use crossterm::style::Stylize;
         use unicode_segmentation::UnicodeSegmentation;
 
         let mut stdout = std::io::stdout();
 
         clear_from(&mut stdout, self.printed_length - self.cursor_offset)?;
 
-        self.printed_length = buffer.graphemes(true).count();
-        self.cursor_offset =
-            self.printed_length - buffer[0..buffer.cursor()].graphemes(true).count();
+        // The placeholder is only a hint, so the cursor stays before it
+        if let Some(placeholder) = self.placeholder.as_deref() {
+            if buffer.is_empty() && completion.is_none_or(|(_, text)| text.is_empty()) {
+                self.printed_length = placeholder.graphemes(true).count();
+                self.cursor_offset = self.printed_length;
+                crossterm::queue!(
+                    &mut stdout,
+                    crossterm::style::PrintStyledContent(
+                        crossterm::style::style(placeholder).dim()
+                    ),
+                )?;
+                rewind_cursor(&mut stdout, self.cursor_offset)?;
+                return crossterm::execute!(&mut stdout);
+            }
+        }
 
-        crossterm::queue!(&mut stdout, crossterm::style::Print(&buffer))?;
+        // The completion is rendered at its position, which may be in the middle of the buffer
+        let (position, completion) = completion.unwrap_or((buffer.len(), ""));
+        let completion_length = completion.graphemes(true).count();
+        let cursor = buffer[0..buffer.cursor()].graphemes(true).count()
+            + if buffer.cursor() > position {
+                completion_length
+            } else {
+                0
+            };
 
-        if let Some(completion) = completion {
-            use crossterm::style::Stylize;
+        self.printed_length = buffer.graphemes(true).count() + completion_length;
+        self.cursor_offset = self.printed_length - cursor;
 
-            let completion_len = completion.graphemes(true).count();
-            crossterm::queue!(
-                &mut stdout,
-                crossterm::style::PrintStyledContent(crossterm::style::style(completion).blue())
-            )?;
-            rewind_cursor(&mut stdout, completion_len)?;
-        }
+        crossterm::queue!(
+            &mut stdout,
+            crossterm::style::Print(&buffer[..position]),
+            crossterm::style::PrintStyledContent(crossterm::style::style(completion).blue()),
+            crossterm::style::Print(&buffer[position..]),
+        )?;
 
         rewind_cursor(&mut stdout, self.cursor_offset)?;
         crossterm::execute!(&mut stdout)
     }
 
-    pub(super) fn print_selected_suggestion(
-        &mut self,
-        selected_index: usize,
-        suggestions: &[std::borrow::Cow<'_, str>],
-    ) -> Result<(), Error> {
-        use unicode_segmentation::UnicodeSegmentation;
+    // Stops showing the placeholder from the next print on, e.g. once the line is finished
+    pub(super) fn hide_placeholder(&mut self) {
+        self.placeholder = None;
+    }
 
+    // Leaves the line as is and hands the terminal over, e.g. to an external editor
+    pub(super) fn suspend(&mut self) -> Result<(), Error> {
         let mut stdout = std::io::stdout();
-
-        // Print buffer
-        let buffer = suggestions[selected_index].as_ref();
-        clear_from(&mut stdout, self.printed_length - self.cursor_offset)?;
-        crossterm::queue!(stdout, crossterm::style::Print(buffer))?;
-        self.cursor_offset = 0;
-        self.printed_length = buffer.graphemes(true).count();
-
-        // Save position at the end of the buffer
-        // TODO: avoid this save and the later restore
-        let end_of_buffer = crossterm::cursor::position().map(|pos| pos.0)?;
-
-        // Restore cursor
-        let bottom_of_buffer = crossterm::cursor::position().map(|pos| pos.1)?;
-        crossterm::queue!(
+        fast_forward_cursor(&mut stdout, self.cursor_offset)?;
+        crossterm::execute!(
             stdout,
-            crossterm::cursor::MoveTo(end_of_buffer, bottom_of_buffer)
+            crossterm::terminal::Clear(crossterm::terminal::ClearType::FromCursorDown),
+            crossterm::event::DisableBracketedPaste,
+            crossterm::style::Print("\r\n")
         )?;
-        rewind_cursor(&mut stdout, self.cursor_offset)?;
+        crossterm::terminal::disable_raw_mode()
+    }
 
-        // Execute
-        crossterm::execute!(stdout)
+    // Takes the terminal back, starting over on a new line
+    pub(super) fn resume(&mut self) -> Result<(), Error> {
+        crossterm::terminal::enable_raw_mode()?;
+        enable_bracketed_paste();
+        self.printed_length = 0;
+        self.cursor_offset = 0;
+        if let Some(prompt) = &self.prompt {
+            crossterm::queue!(std::io::stdout(), crossterm::style::Print(prompt))?;
+        }
+        Ok(())
     }
 
     pub(super) fn print_suggestion_options(
         &mut self,
-        selected_index: usize,
-        suggestions: &[std::borrow::Cow<'_, str>],
+        selected_index: Option<usize>,
+        suggestions: &[Suggestion<'_>],
+        layout: Layout,
+        max_rows: Option<usize>,
     ) -> Result<(), Error> {
-        use unicode_segmentation::UnicodeSegmentation;
+        use crossterm::style::Stylize;
 
         let mut stdout = std::io::stdout();
 
@@ -105,41 +140,66 @@ impl Writer {
         // TODO: avoid this save and the later restore
         let end_of_buffer = crossterm::cursor::position().map(|pos| pos.0)?;
 
+        let terminal_width = usize::from(crossterm::terminal::size()?.0);
+        let grid = Grid::new(suggestions, layout, terminal_width);
+        let rows = max_rows.map_or(grid.rows, |max| max.max(1).min(grid.rows));
+        self.options_offset = scroll(
+            self.options_offset,
+            selected_index.unwrap_or(self.options_offset) % grid.rows,
+            rows,
+            grid.rows,
+        );
+
         // Print suggestions
-        for (index, suggestion) in suggestions.iter().enumerate() {
-            if index == selected_index {
-                use crossterm::style::Stylize;
-                crossterm::queue!(
-                    stdout,
-                    crossterm::style::Print('\n'),
-                    crossterm::cursor::MoveToColumn(0),
-                    crossterm::style::PrintStyledContent(
-                        crossterm::style::style(suggestion).bold()
-                    ),
-                )?;
-            } else {
-                crossterm::queue!(
-                    stdout,
-                    crossterm::style::Print('\n'),
-                    crossterm::cursor::MoveToColumn(0),
-                    crossterm::style::Print(suggestion),
+        for row in self.options_offset..self.options_offset + rows {
+            crossterm::queue!(
+                stdout,
+                crossterm::style::Print('\n'),
+                crossterm::cursor::MoveToColumn(0),
+            )?;
+
+            for column in 0..grid.columns {
+                let index = column * grid.rows + row;
+                let Some(suggestion) = suggestions.get(index) else {
+                    break;
+                };
+
+                if column > 0 {
+                    crossterm::queue!(stdout, crossterm::style::Print(" ".repeat(GRID_SPACING)))?;
+                }
+
+                grid.print_cell(
+                    &mut stdout,
+                    suggestion,
+                    Some(index) == selected_index,
+                    column + 1 < grid.columns,
                 )?;
             }
         }
 
-        // Rewind suggestions cursor
-        for suggestion in suggestions.iter().rev() {
-            let length = suggestion.graphemes(true).count();
-            rewind_cursor(&mut stdout, length)?;
-            crossterm::queue!(stdout, crossterm::cursor::MoveUp(1))?;
-        }
+        // Print the position indicator if not all suggestions fit
+        let printed_rows = if rows < grid.rows {
+            crossterm::queue!(
+                stdout,
+                crossterm::style::Print('\n'),
+                crossterm::cursor::MoveToColumn(0),
+                crossterm::style::PrintStyledContent(
+                    crossterm::style::style(format!(
+                        "{}/{}",
+                        selected_index.map_or(0, |index| index + 1),
+                        suggestions.len()
+                    ))
+                    .dim()
+                ),
+            )?;
+            rows + 1
+        } else {
+            rows
+        };
 
         // Restore cursor
-        let bottom_of_buffer = crossterm::cursor::position().map(|pos| pos.1)?;
-        crossterm::queue!(
-            stdout,
-            crossterm::cursor::MoveTo(end_of_buffer, bottom_of_buffer)
-        )?;
+        move_up(&mut stdout, printed_rows)?;
+        crossterm::queue!(stdout, crossterm::cursor::MoveToColumn(end_of_buffer))?;
         rewind_cursor(&mut stdout, self.cursor_offset)?;
 
         // Execute
@@ -147,6 +207,166 @@ impl Writer {
     }
 }
 
+// Prints `message` underneath the buffer, leaving the cursor where it was
+pub(super) fn print_error(message: &str) -> Result<(), Error> {
+    use crossterm::style::Stylize;
+
+    let mut stdout = std::io::stdout();
+    let column = crossterm::cursor::position()?.0;
+    let terminal_width = usize::from(crossterm::terminal::size()?.0).max(1);
+
+    let mut printed_rows = 0;
+    for line in message.lines() {
+        crossterm::queue!(
+            stdout,
+            crossterm::style::Print('\n'),
+            crossterm::cursor::MoveToColumn(0),
+            crossterm::style::PrintStyledContent(crossterm::style::style(line).red()),
+        )?;
+        printed_rows += display_width(line).max(1).div_ceil(terminal_width);
+    }
+
+    move_up(&mut stdout, printed_rows)?;
+    crossterm::execute!(stdout, crossterm::cursor::MoveToColumn(column))
+}
+
+// Pasted text arrives as a single event, instead of being typed key by key
+fn enable_bracketed_paste() {
+    // Not every terminal supports it, in which case the text is typed instead
+    let _ = crossterm::execute!(std::io::stdout(), crossterm::event::EnableBracketedPaste);
+}
+
+pub(super) fn bell() -> Result<(), Error> {
+    crossterm::execute!(std::io::stdout(), crossterm::style::Print('\x07'))
+}
+
+const GRID_SPACING: usize = 2;
+
+/// The geometry of the suggestion options when rendered with a given [`Layout`].
+///
+/// Options are laid out column-major: the first column is filled top to bottom before moving on
+/// to the next one. If any option has a description, every cell gets an aligned description
+/// column.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(super) struct Grid {
+    columns: usize,
+    rows: usize,
+    width: usize,
+    description_width: usize,
+}
+
+impl Grid {
+    pub(super) fn new(options: &[Suggestion<'_>], layout: Layout, terminal_width: usize) -> Self {
+        let width = options
+            .iter()
+            .map(|option| display_width(option.display()))
+            .max()
+            .unwrap_or(0);
+        let description_width = options
+            .iter()
+            .filter_map(Suggestion::description)
+            .map(display_width)
+            .max()
+            .unwrap_or(0);
+
+        let mut grid = Self {
+            columns: 1,
+            rows: options.len().max(1),
+            width,
+            description_width,
+        };
+
+        if layout == Layout::Grid && !options.is_empty() {
+            let columns = ((terminal_width + GRID_SPACING) / (grid.cell_width() + GRID_SPACING))
+                .max(1)
+                .min(options.len());
+            grid.rows = options.len().div_ceil(columns);
+            grid.columns = options.len().div_ceil(grid.rows);
+        }
+
+        grid
+    }
+
+    pub(super) fn rows(self) -> usize {
+        self.rows
+    }
+
+    fn cell_width(self) -> usize {
+        if self.description_width > 0 {
+            self.width + GRID_SPACING + self.description_width
+        } else {
+            self.width
+        }
+    }
+
+    fn print_cell(
+        self,
+        stdout: &mut std::io::Stdout,
+        suggestion: &Suggestion<'_>,
+        selected: bool,
+        pad: bool,
+    ) -> Result<(), Error> {
+        use crossterm::style::Stylize;
+
+        let display = suggestion.display();
+        let mut style = suggestion.style().unwrap_or_default();
+        if selected {
+            style = style.bold();
+        }
+        if suggestion.highlights().is_empty() {
+            crossterm::queue!(
+                stdout,
+                crossterm::style::PrintStyledContent(style.apply(display))
+            )?;
+        } else {
+            let highlighted = style.underlined();
+            for (index, c) in display.char_indices() {
+                let style = if suggestion.highlights().contains(&index) {
+                    highlighted
+                } else {
+                    style
+                };
+                crossterm::queue!(stdout, crossterm::style::PrintStyledContent(style.apply(c)))?;
+            }
+        }
+
+        let mut printed = display_width(display);
+        if let Some(description) = suggestion.description() {
+            crossterm::queue!(
+                stdout,
+                crossterm::style::Print(" ".repeat(self.width - printed + GRID_SPACING)),
+                crossterm::style::PrintStyledContent(crossterm::style::style(description).dim()),
+            )?;
+            printed = self.width + GRID_SPACING + display_width(description);
+        }
+
+        if pad {
+            crossterm::queue!(
+                stdout,
+                crossterm::style::Print(" ".repeat(self.cell_width() - printed))
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn display_width(string: &str) -> usize {
+    unicode_width::UnicodeWidthStr::width(string)
+}
+
+/// Calculates the first visible row of a scrolling window of `rows` that contains `selected`,
+/// moving as little as possible from the previous `offset`.
+fn scroll(offset: usize, selected: usize, rows: usize, len: usize) -> usize {
+    if selected < offset {
+        selected
+    } else if selected >= offset + rows {
+        selected + 1 - rows
+    } else {
+        offset.min(len.saturating_sub(rows))
+    }
+}
+
 fn clear_from(stdout: &mut std::io::Stdout, amount: usize) -> Result<(), Error> {
     rewind_cursor(stdout, amount)?;
 
@@ -164,14 +384,30 @@ fn rewind_cursor(stdout: &mut std::io::Stdout, amount: usize) -> Result<(), Erro
     }
 
     let mut remaining = amount;
-    while remaining > usize::from(u16::max_value()) {
-        crossterm::queue!(stdout, crossterm::cursor::MoveLeft(u16::max_value()))?;
-        remaining -= usize::from(u16::max_value());
+    while remaining > usize::from(u16::MAX) {
+        crossterm::queue!(stdout, crossterm::cursor::MoveLeft(u16::MAX))?;
+        remaining -= usize::from(u16::MAX);
     }
 
     crossterm::queue!(stdout, crossterm::cursor::MoveLeft(remaining as u16))
 }
 
+// Allowed because we slice `usize` into `u16` chunks
+#[allow(clippy::cast_possible_truncation)]
+fn move_up(stdout: &mut std::io::Stdout, amount: usize) -> Result<(), Error> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let mut remaining = amount;
+    while remaining > usize::from(u16::MAX) {
+        crossterm::queue!(stdout, crossterm::cursor::MoveUp(u16::MAX))?;
+        remaining -= usize::from(u16::MAX);
+    }
+
+    crossterm::queue!(stdout, crossterm::cursor::MoveUp(remaining as u16))
+}
+
 // Allowed because we slice `usize` into `u16` chunks
 #[allow(clippy::cast_possible_truncation)]
 fn fast_forward_cursor(stdout: &mut std::io::Stdout, amount: usize) -> Result<(), Error> {
@@ -180,9 +416,9 @@ fn fast_forward_cursor(stdout: &mut std::io::Stdout, amount: usize) -> Result<()
     }
 
     let mut remaining = amount;
-    while remaining > usize::from(u16::max_value()) {
-        crossterm::queue!(stdout, crossterm::cursor::MoveRight(u16::max_value()))?;
-        remaining -= usize::from(u16::max_value());
+    while remaining > usize::from(u16::MAX) {
+        crossterm::queue!(stdout, crossterm::cursor::MoveRight(u16::MAX))?;
+        remaining -= usize::from(u16::MAX);
     }
 
     crossterm::queue!(stdout, crossterm::cursor::MoveRight(remaining as u16))
@@ -193,6 +429,7 @@ impl std::ops::Drop for Writer {
     #[allow(unused_must_use)]
     fn drop(&mut self) {
         crossterm::terminal::disable_raw_mode();
+        crossterm::execute!(std::io::stdout(), crossterm::event::DisableBracketedPaste);
 
         let mut stdout = std::io::stdout();
 
@@ -208,3 +445,101 @@ impl std::ops::Drop for Writer {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{scroll, Grid, Layout, Suggestion};
+
+    fn options(list: &[&'static str]) -> Vec<Suggestion<'static>> {
+        list.iter().copied().map(Suggestion::from).collect()
+    }
+
+    #[test]
+    fn scroll_keeps_offset_while_selection_is_visible() {
+        assert_eq!(scroll(0, 0, 5, 100), 0);
+        assert_eq!(scroll(0, 4, 5, 100), 0);
+        assert_eq!(scroll(10, 12, 5, 100), 10);
+    }
+
+    #[test]
+    fn scroll_follows_selection_forward() {
+        assert_eq!(scroll(0, 5, 5, 100), 1);
+        assert_eq!(scroll(0, 99, 5, 100), 95);
+    }
+
+    #[test]
+    fn scroll_follows_selection_backward() {
+        assert_eq!(scroll(10, 9, 5, 100), 9);
+        assert_eq!(scroll(95, 0, 5, 100), 0);
+    }
+
+    #[test]
+    fn scroll_clamps_to_the_last_page() {
+        assert_eq!(scroll(98, 99, 5, 100), 95);
+        assert_eq!(scroll(3, 4, 5, 5), 0);
+    }
+
+    #[test]
+    fn list_has_a_single_column() {
+        let grid = Grid::new(&options(&["a", "bb", "ccc"]), Layout::List, 80);
+        assert_eq!(
+            grid,
+            Grid {
+                columns: 1,
+                rows: 3,
+                width: 3,
+                description_width: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn grid_fits_the_terminal_width() {
+        let options = options(&["aaaa", "bb", "cccc", "d", "eeee", "ff", "g"]);
+
+        // Each column takes 4 characters plus 2 of spacing, except for the last one
+        assert_eq!(Grid::new(&options, Layout::Grid, 22).columns, 4);
+        assert_eq!(Grid::new(&options, Layout::Grid, 21).columns, 3);
+        assert_eq!(Grid::new(&options, Layout::Grid, 21).rows, 3);
+        assert_eq!(Grid::new(&options, Layout::Grid, 3).columns, 1);
+    }
+
+    #[test]
+    fn grid_does_not_leave_empty_columns() {
+        // Four columns would fit, but two rows are needed regardless, filling only three columns
+        let options = options(&["a", "b", "c", "d", "e"]);
+        let grid = Grid::new(&options, Layout::Grid, 10);
+        assert_eq!(grid.rows, 2);
+        assert_eq!(grid.columns, 3);
+    }
+
+    // Allowed because it makes test clearer
+    #[allow(clippy::non_ascii_literal)]
+    #[test]
+    fn grid_uses_display_width() {
+        let options = options(&["日本語", "abc", "de"]);
+        let grid = Grid::new(&options, Layout::Grid, 14);
+        assert_eq!(grid.width, 6);
+        assert_eq!(grid.columns, 2);
+    }
+
+    #[test]
+    fn descriptions_are_aligned_in_their_own_column() {
+        let options = vec![
+            Suggestion::new("commit").with_description("Record changes"),
+            Suggestion::new("rm"),
+            Suggestion::new("mv")
+                .with_display("move")
+                .with_description("Move"),
+        ];
+
+        let grid = Grid::new(&options, Layout::List, 80);
+        assert_eq!(grid.width, 6);
+        assert_eq!(grid.description_width, 14);
+        assert_eq!(grid.cell_width(), 22);
+
+        // Two cells take 22 characters each plus 2 of spacing
+        assert_eq!(Grid::new(&options, Layout::Grid, 46).columns, 2);
+        assert_eq!(Grid::new(&options, Layout::Grid, 45).columns, 1);
+    }
+}
@@ -0,0 +1,422 @@
+//! Provides a read-eval-print loop that dispatches lines to named commands.
+//!
+//! A [`Repl`] reads lines with a [`Prompt`], runs the command named by the first word of the line
+//! with the remaining words as arguments, prints any error, and reads the next line, until the
+//! input ends or the built-in `exit` command is run.
+//!
+//! Commands are declared with [`Command`], so their subcommands, flags and arguments are suggested
+//! while typing. The lines entered so far are completed in-line, and recalled with `Up` and
+//! `Down`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rucline::completion::{Command, Paths};
+//! use rucline::prompt::Prompt;
+//! use rucline::repl::Repl;
+//!
+//! let mut repl = Repl::new(Prompt::from("> "))
+//!     .with_command(
+//!         Command::new("greet")
+//!             .with_description("Say hello")
+//!             .with_argument(vec!["world", "there"]),
+//!         |args| {
+//!             println!("Hello {}!", args.join(" "));
+//!             Ok(())
+//!         },
+//!     )
+//!     .with_command(
+//!         Command::new("size").with_argument(Paths::new()),
+//!         |args| {
+//!             for path in args {
+//!                 println!("{path}: {} bytes", std::fs::metadata(path)?.len());
+//!             }
+//!             Ok(())
+//!         },
+//!     );
+//!
+//! repl.run().unwrap();
+//! ```
+//!
+//! [`Command`]: ../completion/struct.Command.html
+//! [`Prompt`]: ../prompt/struct.Prompt.html
+//! [`Repl`]: struct.Repl.html
+
+use crate::actions::{EditorOverrider, Handler};
+use crate::completion::{Command, Completer, Completion};
+use crate::prompt::{Builder, Prompt};
+use crate::{Buffer, Outcome};
+
+use std::io::Write;
+
+/// The result of a command handler. Errors are printed and the loop goes on.
+pub type Result = std::result::Result<(), Box<dyn std::error::Error>>;
+
+type CommandHandler<'a> = Box<dyn FnMut(&[String]) -> Result + 'a>;
+
+const HELP: &str = "help";
+const HELP_DESCRIPTION: &str = "List the commands";
+const EXIT: &str = "exit";
+const EXIT_DESCRIPTION: &str = "Leave";
+
+/// A read-eval-print loop that dispatches lines to named commands.
+///
+/// Besides the registered commands, `help` lists the commands and `exit` ends the loop, as does
+/// the end of the input, e.g. `Ctrl-D` on an empty line. Interrupting or canceling a line
+/// discards it and reads the next one.
+///
+/// The [`Prompt`] configuration is reused for every line, together with the hooks set with
+/// [`with_overrider`], [`with_handler`] and [`with_completer`].
+///
+/// See the [module documentation] for an example.
+///
+/// [`Prompt`]: ../prompt/struct.Prompt.html
+/// [`with_completer`]: #method.with_completer
+/// [`with_handler`]: #method.with_handler
+/// [`with_overrider`]: #method.with_overrider
+/// [module documentation]: index.html
+pub struct Repl<'a> {
+    prompt: Prompt,
+    root: Command<'a>,
+    commands: Vec<(String, Option<String>, CommandHandler<'a>)>,
+    history: Vec<String>,
+    overrider: Option<Box<dyn EditorOverrider + 'a>>,
+    handler: Option<Box<dyn Handler + 'a>>,
+    completer: Option<Box<dyn Completer + 'a>>,
+}
+
+impl<'a> Repl<'a> {
+    /// Creates a new [`Repl`] that reads lines with `prompt`, with only the built-in commands.
+    ///
+    /// [`Repl`]: struct.Repl.html
+    #[must_use]
+    pub fn new(prompt: Prompt) -> Self {
+        Self {
+            prompt,
+            root: Command::new("")
+                .with_subcommand(Command::new(HELP).with_description(HELP_DESCRIPTION))
+                .with_subcommand(Command::new(EXIT).with_description(EXIT_DESCRIPTION)),
+            commands: Vec::new(),
+            history: Vec::new(),
+            overrider: None,
+            handler: None,
+            completer: None,
+        }
+    }
+
+    /// Registers a command, which is run by `handler` when a line starts with its name.
+    ///
+    /// A command with the name of another one replaces it, including the built-in `help` and
+    /// `exit` commands.
+    ///
+    /// # Arguments
+    /// * [`command`] - The name of the command and how to suggest its arguments.
+    /// * `handler` - Runs the command with the remaining words of the line, with quotes and
+    ///   escapes removed, as arguments.
+    ///
+    /// [`command`]: ../completion/struct.Command.html
+    #[must_use]
+    pub fn with_command<F>(mut self, command: Command<'a>, handler: F) -> Self
+    where
+        F: FnMut(&[String]) -> Result + 'a,
+    {
+        self.commands.retain(|(name, _, _)| name != command.name());
+        self.commands.push((
+            command.name().to_owned(),
+            command.description().map(ToOwned::to_owned),
+            Box::new(handler),
+        ));
+        self.root = self
+            .root
+            .without_subcommand(command.name())
+            .with_subcommand(command);
+        self
+    }
+
+    /// Modifies the behavior of the prompt of every line, as [`Builder::overrider`] does.
+    ///
+    /// # Arguments
+    /// * [`overrider`] - The overrider.
+    ///
+    /// [`Builder::overrider`]: ../prompt/trait.Builder.html#method.overrider
+    /// [`overrider`]: ../actions/trait.EditorOverrider.html
+    #[must_use]
+    pub fn with_overrider<O: EditorOverrider + 'a>(mut self, overrider: O) -> Self {
+        self.overrider = Some(Box::new(overrider));
+        self
+    }
+
+    /// Handles the [`Custom`] actions of every line, as [`Builder::handler`] does.
+    ///
+    /// # Arguments
+    /// * [`handler`] - The handler.
+    ///
+    /// [`Builder::handler`]: ../prompt/trait.Builder.html#method.handler
+    /// [`Custom`]: ../actions/enum.Action.html#variant.Custom
+    /// [`handler`]: ../actions/trait.Handler.html
+    #[must_use]
+    pub fn with_handler<H: Handler + 'a>(mut self, handler: H) -> Self {
+        self.handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Completes every line in-line with `completer` first, and then with the lines entered so
+    /// far.
+    ///
+    /// # Arguments
+    /// * [`completer`] - The completer.
+    ///
+    /// [`completer`]: ../completion/trait.Completer.html
+    #[must_use]
+    pub fn with_completer<C: Completer + 'a>(mut self, completer: C) -> Self {
+        self.completer = Some(Box::new(completer));
+        self
+    }
+
+    /// Returns the lines entered so far, from the most recent.
+    #[must_use]
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Reads and runs lines until the input ends or `exit` is run.
+    ///
+    /// # Errors
+    /// * [`Error`] - If an error occurred while reading the user input.
+    ///
+    /// [`Error`]: ../enum.Error.html
+    pub fn run(&mut self) -> std::result::Result<(), crate::Error> {
+        loop {
+            let completer = Completers {
+                first: self.completer.as_deref(),
+                history: &self.history,
+            };
+            let outcome = self.prompt.clone().history(&self.history).read_line_with(
+                self.overrider.as_deref(),
+                Some(&completer),
+                Some(&self.root),
+                self.handler.as_deref(),
+            )?;
+
+            match outcome {
+                Outcome::Accepted(line) => {
+                    if !self.dispatch(&line, &mut std::io::stdout(), &mut std::io::stderr())? {
+                        return Ok(());
+                    }
+                }
+                Outcome::Eof => return Ok(()),
+                Outcome::Canceled(_) | Outcome::Interrupted(_) => {}
+            }
+        }
+    }
+
+    // Runs the command of `line`, printing to `output` and `errors`, returning `false` if the loop
+    // should end
+    fn dispatch(
+        &mut self,
+        line: &str,
+        output: &mut dyn Write,
+        errors: &mut dyn Write,
+    ) -> std::io::Result<bool> {
+        let mut words = Buffer::from(line)
+            .tokens()
+            .into_iter()
+            .map(|token| token.text().to_owned());
+        let Some(name) = words.next() else {
+            return Ok(true);
+        };
+        let args = words.collect::<Vec<_>>();
+
+        self.history.retain(|entry| entry != line);
+        self.history.insert(0, line.to_owned());
+
+        if let Some((_, _, handler)) = self.commands.iter_mut().find(|c| c.0 == name) {
+            if let Err(error) = handler(&args) {
+                writeln!(errors, "{name}: {error}")?;
+            }
+        } else {
+            match name.as_str() {
+                EXIT => return Ok(false),
+                HELP => self.help(output)?,
+                _ => writeln!(errors, "{name}: unknown command, try `{HELP}`")?,
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn help(&self, output: &mut dyn Write) -> std::io::Result<()> {
+        let builtins = [
+            (HELP, Some(HELP_DESCRIPTION)),
+            (EXIT, Some(EXIT_DESCRIPTION)),
+        ];
+        let commands = self
+            .commands
+            .iter()
+            .map(|(name, description, _)| (name.as_str(), description.as_deref()))
+            .chain(
+                builtins
+                    .into_iter()
+                    .filter(|(builtin, _)| !self.commands.iter().any(|c| c.0 == *builtin)),
+            )
+            .collect::<Vec<_>>();
+
+        let width = commands
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        for (name, description) in commands {
+            writeln!(
+                output,
+                "  {name:width$}  {}",
+                description.unwrap_or_default()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// Completes with the completer of the user, if any, and then with the lines entered so far
+struct Completers<'r> {
+    first: Option<&'r dyn Completer>,
+    history: &'r [String],
+}
+
+impl Completer for Completers<'_> {
+    fn complete_for(&self, buffer: &Buffer) -> Option<Completion<'_>> {
+        self.first
+            .and_then(|completer| completer.complete_for(buffer))
+            .or_else(|| self.history.complete_for(buffer))
+    }
+
+    fn is_completion_pending(&self) -> bool {
+        self.first.is_some_and(Completer::is_completion_pending)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Command, Completer, Completers, Prompt, Repl};
+
+    use std::cell::RefCell;
+
+    // Dispatches `line`, returning whether the loop goes on and what was printed to the output
+    // and errors
+    fn dispatch(repl: &mut Repl<'_>, line: &str) -> (bool, String, String) {
+        let mut output = Vec::new();
+        let mut errors = Vec::new();
+        let next = repl.dispatch(line, &mut output, &mut errors).unwrap();
+        (
+            next,
+            String::from_utf8(output).unwrap(),
+            String::from_utf8(errors).unwrap(),
+        )
+    }
+
+    #[test]
+    fn dispatch_to_the_named_command() {
+        let calls = RefCell::new(Vec::new());
+        let mut repl = Repl::new(Prompt::new())
+            .with_command(Command::new("add"), |args| {
+                calls.borrow_mut().push(format!("add {args:?}"));
+                Ok(())
+            })
+            .with_command(Command::new("rm"), |args| {
+                calls.borrow_mut().push(format!("rm {args:?}"));
+                Err("no such file".into())
+            });
+
+        assert_eq!(
+            dispatch(&mut repl, r#"add a "b c""#),
+            (true, String::new(), String::new())
+        );
+        assert_eq!(
+            dispatch(&mut repl, "rm d"),
+            (true, String::new(), String::from("rm: no such file\n"))
+        );
+        assert_eq!(
+            dispatch(&mut repl, "mv e"),
+            (
+                true,
+                String::new(),
+                String::from("mv: unknown command, try `help`\n")
+            )
+        );
+        assert_eq!(
+            dispatch(&mut repl, "  "),
+            (true, String::new(), String::new())
+        );
+        drop(repl);
+
+        assert_eq!(
+            calls.into_inner(),
+            vec![r#"add ["a", "b c"]"#, r#"rm ["d"]"#]
+        );
+    }
+
+    #[test]
+    fn exit_ends_the_loop() {
+        let mut repl = Repl::new(Prompt::new())
+            .with_command(Command::new("add").with_description("Add a file"), |_| {
+                Ok(())
+            });
+        assert_eq!(
+            dispatch(&mut repl, "help"),
+            (
+                true,
+                String::from("  add   Add a file\n  help  List the commands\n  exit  Leave\n"),
+                String::new()
+            )
+        );
+        assert!(!dispatch(&mut repl, "exit").0);
+    }
+
+    #[test]
+    fn replace_the_built_in_commands() {
+        let calls = RefCell::new(0);
+        let mut repl = Repl::new(Prompt::new()).with_command(
+            Command::new("exit").with_description("Save and leave"),
+            |_| {
+                *calls.borrow_mut() += 1;
+                Ok(())
+            },
+        );
+
+        assert!(dispatch(&mut repl, "exit").0);
+        assert_eq!(
+            dispatch(&mut repl, "help").1,
+            "  exit  Save and leave\n  help  List the commands\n"
+        );
+        drop(repl);
+        assert_eq!(calls.into_inner(), 1);
+    }
+
+    #[test]
+    fn keep_the_latest_lines_first() {
+        let mut repl = Repl::new(Prompt::new());
+        dispatch(&mut repl, "help");
+        dispatch(&mut repl, "unknown");
+        dispatch(&mut repl, "help");
+        assert_eq!(repl.history(), &["help", "unknown"]);
+    }
+
+    #[test]
+    fn complete_with_the_completer_before_the_history() {
+        let history = [String::from("git commit"), String::from("cargo test")];
+        let completer = vec!["git checkout"];
+
+        let completers = Completers {
+            first: Some(&completer),
+            history: &history,
+        };
+        assert_eq!(completers.complete_for(&"git c".into()).unwrap(), "heckout");
+        assert_eq!(completers.complete_for(&"car".into()).unwrap(), "go test");
+
+        let completers = Completers {
+            first: None,
+            history: &history,
+        };
+        assert_eq!(completers.complete_for(&"git c".into()).unwrap(), "ommit");
+    }
+}